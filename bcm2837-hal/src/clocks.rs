@@ -1,9 +1,11 @@
 //! Clocks
 //!
-//! WARNING/TODO: assumes `core_clock` is running at the default 250 MHz
-//!
-//! TODO: proper determination of APB clock frequency
+//! `Clocks::read()` assumes `core_clock` is running at the default 250 MHz
+//! and the UART clock at the firmware default 48 MHz, `Clocks::freeze()`
+//! queries the firmware for the actual rates
 
+use mailbox::{Channel, Error, Mailbox};
+use mailbox_msg::{ClockId, GetClockRateCmd, GetClockRateResp, Tag};
 use time::Hertz;
 
 /// Frozen clock frequencies
@@ -13,17 +15,44 @@ use time::Hertz;
 #[derive(Clone, Copy, Debug)]
 pub struct Clocks {
     apbclk: Hertz,
+    uartclk: Hertz,
 }
 
 impl Clocks {
     pub fn read() -> Self {
         Clocks {
             apbclk: Hertz(250_000_000),
+            uartclk: Hertz(48_000_000),
         }
     }
 
+    /// Reads the core and UART clock rates from the firmware
+    pub fn freeze(mbox: &mut Mailbox) -> Result<Self, Error> {
+        Ok(Clocks {
+            apbclk: clock_rate(mbox, ClockId::Core)?,
+            uartclk: clock_rate(mbox, ClockId::Uart)?,
+        })
+    }
+
     /// Returns the frequency of the APB
     pub fn apbclk(&self) -> Hertz {
         self.apbclk
     }
+
+    /// Returns the frequency of the PL011 UART reference clock
+    pub fn uartclk(&self) -> Hertz {
+        self.uartclk
+    }
+}
+
+fn clock_rate(mbox: &mut Mailbox, id: ClockId) -> Result<Hertz, Error> {
+    let resp: GetClockRateResp = mbox
+        .call_batch(Channel::Prop, &GetClockRateCmd { id })?
+        .get()?;
+
+    if (resp.id != id) || (resp.rate.0 == 0) {
+        return Err(Error::InvalidResponse(Tag::GetClockRate));
+    }
+
+    Ok(resp.rate)
 }
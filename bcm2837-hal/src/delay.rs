@@ -1,13 +1,15 @@
 //! Delays
 
+use bcm2837::sys_timer::SYSTIMER;
 use cortex_a::asm;
 use hal::blocking::delay::{DelayMs, DelayUs};
 
 use clocks::Clocks;
+use timer;
 
 /// NOP used as a delay provider
-/// NOTE: this is not accurate, for accurate timing and delays consider
-/// using one of the timers
+/// NOTE: this is not accurate, for accurate timing and delays use
+/// `Delay`
 pub fn delay_us(us: u32) {
     let cnt = us * (Clocks::read().apbclk().0 / 250_000_000);
 
@@ -16,17 +18,31 @@ pub fn delay_us(us: u32) {
     }
 }
 
-pub struct Delay {}
+/// Delay provider backed by the free-running system timer counter,
+/// leaves the compare channels untouched
+pub struct Delay {
+    timer: SYSTIMER,
+}
 
 impl Delay {
-    pub fn new() -> Self {
-        Delay {}
+    pub fn new(timer: SYSTIMER) -> Self {
+        Delay { timer }
+    }
+
+    pub fn free(self) -> SYSTIMER {
+        self.timer
+    }
+
+    fn delay_ticks(&self, ticks: u64) {
+        let start = timer::counter(&self.timer);
+
+        while timer::counter(&self.timer).wrapping_sub(start) < ticks {}
     }
 }
 
 impl DelayMs<u32> for Delay {
     fn delay_ms(&mut self, ms: u32) {
-        self.delay_us(ms * 1_000);
+        self.delay_ticks(ms as u64 * 1_000);
     }
 }
 
@@ -44,7 +60,7 @@ impl DelayMs<u8> for Delay {
 
 impl DelayUs<u32> for Delay {
     fn delay_us(&mut self, us: u32) {
-        delay_us(us);
+        self.delay_ticks(us as u64);
     }
 }
 
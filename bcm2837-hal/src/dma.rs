@@ -1,17 +1,45 @@
 //! DMA
 
 // TODO
-// - generate all the channels via macros
-// - following https://github.com/rust-embedded/embedded-hal/issues/37#issuecomment-377823801
-// - use the above to make a safe api rather than just control block addr's
+// - 2D and chained transfers with the owned Transfer api
 // - fix the sync/fences/barriers
 
 use bcm2837::dma::*;
+use core::marker::PhantomData;
+use core::mem;
 use core::ops::Deref;
 use core::sync::atomic::{compiler_fence, Ordering};
 use cortex_a::{asm, barrier};
 
 use cache::bus_address_bits;
+use pmem::PMem;
+
+/// Peripheral DREQ signals, used as the `peripheral_map` of a transfer
+pub mod dreq {
+    pub const ALWAYS_ON: u8 = 0;
+    pub const PCM_TX: u8 = 2;
+    pub const PCM_RX: u8 = 3;
+    pub const PWM: u8 = 5;
+    pub const SPI_TX: u8 = 6;
+    pub const SPI_RX: u8 = 7;
+    pub const EMMC: u8 = 11;
+    pub const UART_TX: u8 = 12;
+    pub const UART_RX: u8 = 14;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Error {
+    /// Every control block of the pool is in use
+    PoolExhausted,
+    /// The chain has no control blocks
+    EmptyChain,
+    /// The channel reported errors, see `errors()`
+    TransferFailed,
+    /// The transfer is longer than the channel or the destination supports
+    TransferLength,
+    #[doc(hidden)]
+    _Extensible,
+}
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum TransferLength {
@@ -103,15 +131,15 @@ impl From<&ControlBlockConfig> for u32 {
         }
 
         if config.burst_length != 0 {
-            val |= (config.burst_length as u32) & 0x0F << 12;
+            val |= ((config.burst_length as u32) & 0x0F) << 12;
         }
 
         if config.peripheral_map != 0 {
-            val |= (config.peripheral_map as u32) & 0x1F << 16;
+            val |= ((config.peripheral_map as u32) & 0x1F) << 16;
         }
 
         if config.waits != 0 {
-            val |= (config.waits as u32) & 0x1F << 21;
+            val |= ((config.waits as u32) & 0x1F) << 21;
         }
 
         if config.no_wide_bursts {
@@ -122,6 +150,49 @@ impl From<&ControlBlockConfig> for u32 {
     }
 }
 
+/// Control block configuration for the lite channels, which don't
+/// support 2D mode or 128 bit wide transfers
+#[derive(Debug, Copy, Clone, Default)]
+pub struct LiteControlBlockConfig {
+    pub int_enable: bool,
+    /// Transfer length in bytes, at most `LiteChannel::MAX_TRANSFER_LENGTH`
+    /// on a lite channel
+    pub transfer_length: u32,
+    pub wait_for_resp: bool,
+    pub dest_inc: bool,
+    pub dest_dreq: bool,
+    pub dest_ignore: bool,
+    pub src_inc: bool,
+    pub src_dreq: bool,
+    pub src_ignore: bool,
+    pub burst_length: u8,
+    pub peripheral_map: u8,
+    pub waits: u8,
+    pub no_wide_bursts: bool,
+}
+
+impl From<&LiteControlBlockConfig> for ControlBlockConfig {
+    fn from(config: &LiteControlBlockConfig) -> ControlBlockConfig {
+        ControlBlockConfig {
+            int_enable: config.int_enable,
+            transfer_length: TransferLength::ModeLinear(config.transfer_length),
+            wait_for_resp: config.wait_for_resp,
+            dest_inc: config.dest_inc,
+            dest_width_128: false,
+            dest_dreq: config.dest_dreq,
+            dest_ignore: config.dest_ignore,
+            src_inc: config.src_inc,
+            src_width_128: false,
+            src_dreq: config.src_dreq,
+            src_ignore: config.src_ignore,
+            burst_length: config.burst_length,
+            peripheral_map: config.peripheral_map,
+            waits: config.waits,
+            no_wide_bursts: config.no_wide_bursts,
+        }
+    }
+}
+
 pub const CONTROL_BLOCK_SIZE: usize = 8 * 4;
 
 /// 8 words (256 bits) in length and must start at a 256-bit aligned address
@@ -189,6 +260,11 @@ impl ControlBlock {
         self.__reserved_0[1] = 0;
     }
 
+    /// Same as `config()`, for a control block run by a `LiteChannel`
+    pub fn config_lite(&mut self, config: &LiteControlBlockConfig, src: u32, dst: u32, next: u32) {
+        self.config(&config.into(), src, dst, 0, 0, next);
+    }
+
     pub fn set_2d_mode_length(&mut self, x_len: u16, y_len: u16) {
         // TODO - enforce/assert y_len to 14 bits
         self.length = x_len as u32 & 0x0000_FFFF;
@@ -212,11 +288,26 @@ pub trait DmaExt {
     fn split(self) -> Self::Parts;
 }
 
-#[derive(Debug, Copy, Clone)]
+/// Channels 0 to 6 and 15 are full channels, 7 to 14 are lite channels
+#[derive(Debug)]
 pub struct Parts {
     pub ch0: Channel,
     pub ch1: Channel,
-    // ... 15
+    pub ch2: Channel,
+    pub ch3: Channel,
+    pub ch4: Channel,
+    pub ch5: Channel,
+    pub ch6: Channel,
+    pub ch7: LiteChannel,
+    pub ch8: LiteChannel,
+    pub ch9: LiteChannel,
+    pub ch10: LiteChannel,
+    pub ch11: LiteChannel,
+    pub ch12: LiteChannel,
+    pub ch13: LiteChannel,
+    pub ch14: LiteChannel,
+    /// Only present when split from `(DMA, DMA15)`
+    pub ch15: Option<Channel>,
     pub int_status: IntStatusRegister,
     pub enable: EnableRegister,
 }
@@ -231,16 +322,20 @@ pub struct EnableRegister {
     addr: *const u64,
 }
 
-#[derive(Debug, Copy, Clone)]
+/// Full DMA channel
+#[derive(Debug)]
 pub struct Channel {
     addr: *const u64,
 }
 
-impl Deref for Channel {
-    type Target = RegisterBlock;
-    fn deref(&self) -> &Self::Target {
-        unsafe { &*(self.addr as *const RegisterBlock) }
-    }
+/// Lite DMA channel, no 2D mode or 128 bit wide transfers, see
+/// `LiteControlBlockConfig`
+///
+/// Only runs lists built with `ControlBlockChain::push_lite()`, see
+/// `start_list()`
+#[derive(Debug)]
+pub struct LiteChannel {
+    addr: *const u64,
 }
 
 impl Deref for EnableRegister {
@@ -257,107 +352,652 @@ impl Deref for IntStatusRegister {
     }
 }
 
+fn split_channels(base_vaddr: u64, ch15: Option<Channel>) -> Parts {
+    let ch = |offset: u64| (base_vaddr + offset) as *const u64;
+
+    Parts {
+        ch0: Channel {
+            addr: ch(CHANNEL0_OFFSET),
+        },
+        ch1: Channel {
+            addr: ch(CHANNEL1_OFFSET),
+        },
+        ch2: Channel {
+            addr: ch(CHANNEL2_OFFSET),
+        },
+        ch3: Channel {
+            addr: ch(CHANNEL3_OFFSET),
+        },
+        ch4: Channel {
+            addr: ch(CHANNEL4_OFFSET),
+        },
+        ch5: Channel {
+            addr: ch(CHANNEL5_OFFSET),
+        },
+        ch6: Channel {
+            addr: ch(CHANNEL6_OFFSET),
+        },
+        ch7: LiteChannel {
+            addr: ch(CHANNEL7_OFFSET),
+        },
+        ch8: LiteChannel {
+            addr: ch(CHANNEL8_OFFSET),
+        },
+        ch9: LiteChannel {
+            addr: ch(CHANNEL9_OFFSET),
+        },
+        ch10: LiteChannel {
+            addr: ch(CHANNEL10_OFFSET),
+        },
+        ch11: LiteChannel {
+            addr: ch(CHANNEL11_OFFSET),
+        },
+        ch12: LiteChannel {
+            addr: ch(CHANNEL12_OFFSET),
+        },
+        ch13: LiteChannel {
+            addr: ch(CHANNEL13_OFFSET),
+        },
+        ch14: LiteChannel {
+            addr: ch(CHANNEL14_OFFSET),
+        },
+        ch15,
+        int_status: IntStatusRegister {
+            addr: ch(INT_STATUS_OFFSET),
+        },
+        enable: EnableRegister {
+            addr: ch(ENABLE_OFFSET),
+        },
+    }
+}
+
 impl DmaExt for DMA {
     type Parts = Parts;
 
     fn split(self) -> Parts {
-        let base_vaddr = self.as_ptr() as u64;
-
-        Parts {
-            ch0: Channel {
-                addr: (base_vaddr + CHANNEL0_OFFSET) as _,
-            },
-            ch1: Channel {
-                addr: (base_vaddr + CHANNEL1_OFFSET) as _,
-            },
-            int_status: IntStatusRegister {
-                addr: (base_vaddr + INT_STATUS_OFFSET) as _,
-            },
-            enable: EnableRegister {
-                addr: (base_vaddr + ENABLE_OFFSET) as _,
-            },
-        }
+        split_channels(self.as_ptr() as u64, None)
     }
 }
 
-impl Channel {
-    pub fn is_lite(&self) -> bool {
-        self.DEBUG.is_set(DEBUG::LITE)
-    }
+/// Channel 15 has its own mapping
+impl DmaExt for (DMA, DMA15) {
+    type Parts = Parts;
 
-    pub fn dma_id(&self) -> u8 {
-        self.DEBUG.read(DEBUG::DMA_ID) as _
+    fn split(self) -> Parts {
+        let ch15 = Channel {
+            addr: self.1.as_ptr() as _,
+        };
+
+        split_channels(self.0.as_ptr() as u64, Some(ch15))
     }
+}
 
-    pub fn reset(&self) {
-        // TODO - abort first?
-        self.CS.write(CS::RESET::SET);
-        while self.CS.is_set(CS::RESET) == true {}
+mod private {
+    /// Raw start, kept out of reach so a lite channel only ever loads
+    /// control blocks built for it
+    pub trait Sealed {
+        fn start_paddr(&mut self, cb_paddr: u32);
     }
+}
 
-    pub fn is_busy(&self) -> bool {
-        // TODO - dsb(sy)?
-        //unsafe { barrier::dsb(barrier::SY) };
+/// Operations a `Transfer` needs from a channel
+pub trait DmaChannel: private::Sealed + Sized {
+    /// Largest linear transfer, in bytes
+    const MAX_TRANSFER_LENGTH: usize;
 
-        self.CS.is_set(CS::ACTIVE)
-    }
+    fn start_list<'a>(&'a mut self, list: ControlBlockList<'a, Self>) -> ListTransfer<'a, Self>;
+    fn is_busy(&self) -> bool;
+    fn wait(&self);
+    fn errors(&self) -> bool;
+}
 
-    pub fn wait(&self) {
-        // TODO - dsb(sy)?
-        unsafe { barrier::dsb(barrier::SY) };
+macro_rules! channels {
+    ($($CH:ident: $MAX_LEN:expr,)+) => {
+        $(
+            impl private::Sealed for $CH {
+                fn start_paddr(&mut self, cb_paddr: u32) {
+                    $CH::start_paddr(self, cb_paddr)
+                }
+            }
 
-        while self.CS.is_set(CS::ACTIVE) {
-            asm::nop();
-        }
+            impl DmaChannel for $CH {
+                const MAX_TRANSFER_LENGTH: usize = $MAX_LEN;
+
+                fn start_list<'a>(
+                    &'a mut self,
+                    list: ControlBlockList<'a, $CH>,
+                ) -> ListTransfer<'a, $CH> {
+                    $CH::start_list(self, list)
+                }
 
-        // TODO
-        compiler_fence(Ordering::SeqCst);
+                fn is_busy(&self) -> bool {
+                    $CH::is_busy(self)
+                }
+
+                fn wait(&self) {
+                    $CH::wait(self)
+                }
+
+                fn errors(&self) -> bool {
+                    $CH::errors(self)
+                }
+            }
+
+            impl $CH {
+                fn regs(&self) -> &RegisterBlock {
+                    unsafe { &*(self.addr as *const RegisterBlock) }
+                }
+
+                pub fn is_lite(&self) -> bool {
+                    self.regs().DEBUG.is_set(DEBUG::LITE)
+                }
+
+                pub fn dma_id(&self) -> u8 {
+                    self.regs().DEBUG.read(DEBUG::DMA_ID) as _
+                }
+
+                pub fn reset(&self) {
+                    // TODO - abort first?
+                    self.regs().CS.write(CS::RESET::SET);
+                    while self.regs().CS.is_set(CS::RESET) == true {}
+                }
+
+                pub fn is_busy(&self) -> bool {
+                    // TODO - dsb(sy)?
+                    //unsafe { barrier::dsb(barrier::SY) };
+
+                    self.regs().CS.is_set(CS::ACTIVE)
+                }
+
+                pub fn wait(&self) {
+                    // TODO - dsb(sy)?
+                    unsafe { barrier::dsb(barrier::SY) };
+
+                    while self.regs().CS.is_set(CS::ACTIVE) {
+                        asm::nop();
+                    }
+
+                    // TODO
+                    compiler_fence(Ordering::SeqCst);
+                }
+
+                pub fn abort(&self) {
+                    // TODO
+                    unimplemented!();
+                }
+
+                fn start_paddr(&mut self, cb_paddr: u32) {
+                    assert_eq!(
+                        cb_paddr & 0x1F,
+                        0,
+                        "Control block address must be 256 bit aligned"
+                    );
+
+                    // TODO - dsb(sy)?
+                    unsafe { barrier::dsb(barrier::SY) };
+
+                    self.regs()
+                        .CONBLK_AD
+                        .set(cb_paddr | bus_address_bits::ALIAS_4_L2_COHERENT);
+                    self.regs().CS.write(CS::ACTIVE::SET);
+                }
+
+                /// Runs every transfer of the list, in order
+                ///
+                /// The channel and the list's pool stay borrowed until the
+                /// returned `ListTransfer` is done
+                pub fn start_list<'a>(
+                    &'a mut self,
+                    list: ControlBlockList<'a, $CH>,
+                ) -> ListTransfer<'a, $CH> {
+                    self.start_paddr(list.paddr());
+
+                    ListTransfer {
+                        channel: self,
+                        _list: list,
+                    }
+                }
+
+                /// The channel stopped on an error, unlike `errors()` this
+                /// can be polled while a transfer is active
+                pub fn has_error(&self) -> bool {
+                    self.regs().CS.is_set(CS::ERROR)
+                }
+
+                pub fn errors(&self) -> bool {
+                    if self.regs().CS.is_set(CS::ERROR) {
+                        return true;
+                    }
+
+                    if self.regs().DEBUG.is_set(DEBUG::READ_LAST_NOT_SET_ERROR) {
+                        return true;
+                    }
+
+                    if self.regs().DEBUG.is_set(DEBUG::FIFO_ERROR) {
+                        return true;
+                    }
+
+                    if self.regs().DEBUG.is_set(DEBUG::READ_ERROR) {
+                        return true;
+                    }
+
+                    if self.regs().DEBUG.read(DEBUG::OUTSTANDING_WRITES) != 0 {
+                        return true;
+                    }
+
+                    false
+                }
+            }
+        )+
     }
+}
 
-    pub fn abort(&self) {
-        // TODO
-        unimplemented!();
+channels!(
+    Channel: 0x3FFF_FFFF,
+    LiteChannel: 0xFFFF,
+);
+
+impl Deref for Channel {
+    type Target = RegisterBlock;
+    fn deref(&self) -> &Self::Target {
+        self.regs()
     }
+}
 
+impl Channel {
     /// cb_paddr - the physical address of the control block to load
     /// NOTE: the physical address will be translated to a bus address for
     /// the DMA engine
+    ///
+    /// Lite channels can only be started with `start_list()`, so they only
+    /// ever load control blocks built for them
     pub fn start(&mut self, cb_paddr: u32) {
+        self.start_paddr(cb_paddr)
+    }
+}
+
+/// Memory a `Transfer` can read from or write to
+///
+/// Unsafe to implement, the memory must only be reachable through the
+/// implementor so it can't be touched while a transfer owns it
+pub unsafe trait DmaBuffer {
+    /// Physical address, translated to a bus address for the DMA engine
+    fn paddr(&self) -> u32;
+    /// Size in bytes
+    fn size(&self) -> usize;
+}
+
+/// A `PMem` region with a single owner, unlike `PMem` it is neither
+/// `Copy` nor `Clone`
+#[derive(Debug)]
+pub struct Buffer {
+    pmem: PMem,
+}
+
+impl Buffer {
+    /// Unsafe because nothing else may access the memory, through another
+    /// `PMem` or otherwise, for as long as the `Buffer` exists
+    pub unsafe fn new(pmem: PMem) -> Self {
+        Buffer { pmem }
+    }
+
+    pub fn as_slice<T>(&self, count: usize) -> &[T] {
+        assert!(count * mem::size_of::<T>() <= self.pmem.size());
+        self.pmem.as_slice(count)
+    }
+
+    pub fn as_mut_slice<T>(&mut self, count: usize) -> &mut [T] {
+        assert!(count * mem::size_of::<T>() <= self.pmem.size());
+        self.pmem.as_mut_slice(count)
+    }
+
+    pub fn free(self) -> PMem {
+        self.pmem
+    }
+}
+
+unsafe impl DmaBuffer for Buffer {
+    fn paddr(&self) -> u32 {
+        self.pmem.paddr()
+    }
+
+    fn size(&self) -> usize {
+        self.pmem.size()
+    }
+}
+
+/// A linear transfer in flight
+///
+/// Owns the channel, the control blocks and both buffers until `wait()`
+/// gives them back, dropping it waits for the channel
+#[derive(Debug)]
+pub struct Transfer<CH: DmaChannel, SRC: DmaBuffer, DST: DmaBuffer> {
+    /// Only taken by `wait()`
+    resources: Option<(CH, ControlBlockPool, SRC, DST)>,
+}
+
+/// A transfer that couldn't be started, along with its resources
+pub type TransferError<CH, SRC, DST> = (Error, CH, ControlBlockPool, SRC, DST);
+
+impl<CH, SRC, DST> Transfer<CH, SRC, DST>
+where
+    CH: DmaChannel,
+    SRC: DmaBuffer,
+    DST: DmaBuffer,
+{
+    /// Copies all of `src` to the front of `dst`
+    pub fn copy(
+        channel: CH,
+        control_blocks: ControlBlockPool,
+        src: SRC,
+        dst: DST,
+    ) -> Result<Self, TransferError<CH, SRC, DST>> {
+        if src.size() > dst.size() {
+            return Err((Error::TransferLength, channel, control_blocks, src, dst));
+        }
+        let length = src.size();
+
+        Transfer::start(channel, control_blocks, src, dst, true, length)
+    }
+
+    /// Fills all of `dst` by repeatedly reading the start of `src`
+    ///
+    /// `src` should hold at least 4 words, enough for a full burst
+    pub fn fill(
+        channel: CH,
+        control_blocks: ControlBlockPool,
+        src: SRC,
+        dst: DST,
+    ) -> Result<Self, TransferError<CH, SRC, DST>> {
+        let length = dst.size();
+
+        Transfer::start(channel, control_blocks, src, dst, false, length)
+    }
+
+    fn start(
+        mut channel: CH,
+        mut control_blocks: ControlBlockPool,
+        src: SRC,
+        dst: DST,
+        src_inc: bool,
+        length: usize,
+    ) -> Result<Self, TransferError<CH, SRC, DST>> {
+        if length > CH::MAX_TRANSFER_LENGTH {
+            return Err((Error::TransferLength, channel, control_blocks, src, dst));
+        }
+
+        let config = LiteControlBlockConfig {
+            transfer_length: length as _,
+            wait_for_resp: true,
+            dest_inc: true,
+            src_inc,
+            ..Default::default()
+        };
+
+        // The pool is owned by the transfer from here on, so nothing else
+        // can reuse the block while the channel reads it
+        control_blocks.reset();
+        let index = match control_blocks.alloc() {
+            Ok(index) => index,
+            Err(e) => return Err((e, channel, control_blocks, src, dst)),
+        };
+        control_blocks
+            .block(index)
+            .config_lite(&config, src.paddr(), dst.paddr(), 0);
+
+        channel.start_paddr(control_blocks.paddr(index));
+
+        Ok(Transfer {
+            resources: Some((channel, control_blocks, src, dst)),
+        })
+    }
+
+    pub fn is_done(&self) -> bool {
+        match self.resources {
+            Some((ref channel, ..)) => !channel.is_busy(),
+            None => true,
+        }
+    }
+
+    /// Blocks until the transfer is done and gives back the resources,
+    /// along with the result of the transfer
+    pub fn wait(mut self) -> (CH, ControlBlockPool, SRC, DST, Result<(), Error>) {
+        let (channel, control_blocks, src, dst) = self
+            .resources
+            .take()
+            .expect("Transfer resources are only taken by wait");
+
+        channel.wait();
+
+        let result = if channel.errors() {
+            Err(Error::TransferFailed)
+        } else {
+            Ok(())
+        };
+
+        (channel, control_blocks, src, dst, result)
+    }
+}
+
+impl<CH, SRC, DST> Drop for Transfer<CH, SRC, DST>
+where
+    CH: DmaChannel,
+    SRC: DmaBuffer,
+    DST: DmaBuffer,
+{
+    fn drop(&mut self) {
+        if let Some((ref channel, ..)) = self.resources {
+            channel.wait();
+        }
+    }
+}
+
+/// Hands out control blocks from a `PMem` region
+///
+/// Blocks are only given back all at once, by `reset()`
+#[derive(Debug)]
+pub struct ControlBlockPool {
+    pmem: PMem,
+    capacity: usize,
+    used: usize,
+}
+
+/// Builds a list of transfers for channel type `CH`, each control block
+/// is linked to the next through its `next` address
+#[derive(Debug)]
+pub struct ControlBlockChain<'a, CH> {
+    pool: &'a mut ControlBlockPool,
+    first: usize,
+    len: usize,
+    _channel: PhantomData<CH>,
+}
+
+/// A linked list of control blocks, started with `start_list()`
+///
+/// Borrows its pool, so the pool can't be reset while the list is in use
+#[derive(Debug)]
+pub struct ControlBlockList<'a, CH> {
+    _pool: &'a ControlBlockPool,
+    paddr: u32,
+    _channel: PhantomData<CH>,
+}
+
+/// A control block list in flight, dropping it waits for the channel
+#[derive(Debug)]
+pub struct ListTransfer<'a, CH: 'a + DmaChannel> {
+    channel: &'a mut CH,
+    _list: ControlBlockList<'a, CH>,
+}
+
+impl ControlBlockPool {
+    /// `pmem` must be 256 bit aligned, partial blocks at the end are unused
+    ///
+    /// Unsafe because nothing else may access the memory while the pool
+    /// exists, a running list reads its control blocks from it
+    pub unsafe fn new(pmem: PMem) -> Self {
         assert_eq!(
-            cb_paddr & 0x1F,
+            pmem.paddr() & 0x1F,
             0,
             "Control block address must be 256 bit aligned"
         );
+        let capacity = pmem.size() / CONTROL_BLOCK_SIZE;
+        assert_ne!(capacity, 0);
+
+        ControlBlockPool {
+            pmem,
+            capacity,
+            used: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of blocks not yet handed out
+    pub fn available(&self) -> usize {
+        self.capacity - self.used
+    }
+
+    /// Frees every block
+    pub fn reset(&mut self) {
+        self.used = 0;
+    }
 
-        // TODO - dsb(sy)?
-        unsafe { barrier::dsb(barrier::SY) };
+    /// Starts a new list of transfers
+    pub fn chain<'a, CH>(&'a mut self) -> ControlBlockChain<'a, CH> {
+        let first = self.used;
 
-        self.CONBLK_AD
-            .set(cb_paddr | bus_address_bits::ALIAS_4_L2_COHERENT);
-        self.CS.write(CS::ACTIVE::SET);
+        ControlBlockChain {
+            pool: self,
+            first,
+            len: 0,
+            _channel: PhantomData,
+        }
+    }
+
+    fn paddr(&self, index: usize) -> u32 {
+        self.pmem.paddr() + (index * CONTROL_BLOCK_SIZE) as u32
     }
 
-    pub fn errors(&self) -> bool {
-        if self.CS.is_set(CS::ERROR) {
-            return true;
+    fn block(&mut self, index: usize) -> &mut ControlBlock {
+        &mut self.pmem.as_mut_slice::<ControlBlock>(self.capacity)[index]
+    }
+
+    fn alloc(&mut self) -> Result<usize, Error> {
+        if self.used == self.capacity {
+            return Err(Error::PoolExhausted);
         }
 
-        if self.DEBUG.is_set(DEBUG::READ_LAST_NOT_SET_ERROR) {
-            return true;
+        let index = self.used;
+        self.used += 1;
+        self.block(index).init();
+
+        Ok(index)
+    }
+}
+
+impl<'a, CH> ControlBlockChain<'a, CH> {
+    /// Number of transfers in the chain
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn finish(self) -> Result<ControlBlockList<'a, CH>, Error> {
+        if self.len == 0 {
+            return Err(Error::EmptyChain);
         }
 
-        if self.DEBUG.is_set(DEBUG::FIFO_ERROR) {
-            return true;
+        let pool: &'a ControlBlockPool = self.pool;
+
+        Ok(ControlBlockList {
+            _pool: pool,
+            paddr: pool.paddr(self.first),
+            _channel: PhantomData,
+        })
+    }
+
+    /// Allocates the next block and links the previous one to it
+    fn push_block(&mut self) -> Result<&mut ControlBlock, Error> {
+        let index = self.pool.alloc()?;
+
+        if self.len != 0 {
+            let next = self.pool.paddr(index) | bus_address_bits::ALIAS_4_L2_COHERENT;
+            self.pool.block(index - 1).next = next;
         }
+        self.len += 1;
 
-        if self.DEBUG.is_set(DEBUG::READ_ERROR) {
-            return true;
+        Ok(self.pool.block(index))
+    }
+}
+
+impl<'a> ControlBlockChain<'a, Channel> {
+    /// Appends a transfer, same parameters as `ControlBlock::config()`
+    pub fn push(
+        &mut self,
+        config: &ControlBlockConfig,
+        src: u32,
+        dst: u32,
+        src_stride: u16,
+        dst_stride: u16,
+    ) -> Result<(), Error> {
+        let cb = self.push_block()?;
+        cb.config(config, src, dst, src_stride, dst_stride, 0);
+
+        Ok(())
+    }
+}
+
+impl<'a, CH: DmaChannel> ControlBlockChain<'a, CH> {
+    /// Appends a linear transfer that any channel can run, same parameters
+    /// as `ControlBlock::config_lite()`
+    pub fn push_lite(
+        &mut self,
+        config: &LiteControlBlockConfig,
+        src: u32,
+        dst: u32,
+    ) -> Result<(), Error> {
+        if config.transfer_length as usize > CH::MAX_TRANSFER_LENGTH {
+            return Err(Error::TransferLength);
         }
 
-        if self.DEBUG.read(DEBUG::OUTSTANDING_WRITES) != 0 {
-            return true;
+        let cb = self.push_block()?;
+        cb.config_lite(config, src, dst, 0);
+
+        Ok(())
+    }
+}
+
+impl<'a, CH> ControlBlockList<'a, CH> {
+    /// Physical address of the first control block
+    pub fn paddr(&self) -> u32 {
+        self.paddr
+    }
+}
+
+impl<'a, CH: DmaChannel> ListTransfer<'a, CH> {
+    pub fn is_done(&self) -> bool {
+        !self.channel.is_busy()
+    }
+
+    /// Blocks until every transfer of the list is done
+    pub fn wait(self) -> Result<(), Error> {
+        self.channel.wait();
+
+        if self.channel.errors() {
+            Err(Error::TransferFailed)
+        } else {
+            Ok(())
         }
+    }
+}
 
-        false
+impl<'a, CH: DmaChannel> Drop for ListTransfer<'a, CH> {
+    fn drop(&mut self) {
+        self.channel.wait();
     }
 }
@@ -0,0 +1,215 @@
+//! EDID 1.3/1.4 base block parser
+//!
+//! Read the block with a `GetEdidBlockCmd`
+
+use heapless::consts::{U16, U32, U4};
+use heapless::{String, Vec};
+use mailbox_msg::EDID_BLOCK_LEN;
+use time::Hertz;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Error {
+    /// The fixed header pattern doesn't match
+    BadHeader,
+    /// The block doesn't sum to zero
+    BadChecksum,
+    /// Not an EDID 1.x block
+    UnsupportedVersion,
+    #[doc(hidden)]
+    _Extensible,
+}
+
+const HEADER: [u8; 8] = [0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00];
+
+/// Offsets of the four 18 byte descriptors
+const DESCRIPTORS: [usize; 4] = [54, 72, 90, 108];
+const DESCRIPTOR_LEN: usize = 18;
+
+/// Display descriptor tag of the monitor name
+const DESCRIPTOR_MONITOR_NAME: u8 = 0xFC;
+
+/// Established timings, bit 7 of byte 35 first
+const ESTABLISHED_MODES: [(u32, u32, u32); 17] = [
+    (720, 400, 70),
+    (720, 400, 88),
+    (640, 480, 60),
+    (640, 480, 67),
+    (640, 480, 72),
+    (640, 480, 75),
+    (800, 600, 56),
+    (800, 600, 60),
+    (800, 600, 72),
+    (800, 600, 75),
+    (832, 624, 75),
+    (1024, 768, 87),
+    (1024, 768, 60),
+    (1024, 768, 70),
+    (1024, 768, 75),
+    (1280, 1024, 75),
+    (1152, 870, 75),
+];
+
+/// A supported resolution and refresh rate
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Mode {
+    pub width: u32,
+    pub height: u32,
+    /// Hz
+    pub refresh_rate: u32,
+}
+
+/// Detailed timing descriptor
+#[derive(Debug, Copy, Clone)]
+pub struct DetailedTiming {
+    pub pixel_clock: Hertz,
+    pub h_active: u32,
+    pub h_blank: u32,
+    pub h_sync_offset: u32,
+    pub h_sync_width: u32,
+    pub v_active: u32,
+    pub v_blank: u32,
+    pub v_sync_offset: u32,
+    pub v_sync_width: u32,
+    /// Image size in mm
+    pub width_mm: u32,
+    pub height_mm: u32,
+    pub interlaced: bool,
+}
+
+/// Parsed base block
+#[derive(Debug, Clone)]
+pub struct Edid {
+    pub version: u8,
+    pub revision: u8,
+    /// Physical size in cm, zero if unknown or variable
+    pub width_cm: u8,
+    pub height_cm: u8,
+    /// The first detailed timing, the panel's native mode
+    pub preferred: Option<DetailedTiming>,
+    pub detailed_timings: Vec<DetailedTiming, U4>,
+    /// Established and standard timings
+    pub modes: Vec<Mode, U32>,
+    pub monitor_name: String<U16>,
+}
+
+impl DetailedTiming {
+    fn parse(d: &[u8]) -> Self {
+        let pixel_clock = u32::from(d[0]) | (u32::from(d[1]) << 8);
+
+        DetailedTiming {
+            // 10 kHz units
+            pixel_clock: Hertz(pixel_clock * 10_000),
+            h_active: u32::from(d[2]) | (u32::from(d[4] >> 4) << 8),
+            h_blank: u32::from(d[3]) | (u32::from(d[4] & 0xF) << 8),
+            v_active: u32::from(d[5]) | (u32::from(d[7] >> 4) << 8),
+            v_blank: u32::from(d[6]) | (u32::from(d[7] & 0xF) << 8),
+            h_sync_offset: u32::from(d[8]) | (u32::from((d[11] >> 6) & 0x3) << 8),
+            h_sync_width: u32::from(d[9]) | (u32::from((d[11] >> 4) & 0x3) << 8),
+            v_sync_offset: u32::from(d[10] >> 4) | (u32::from((d[11] >> 2) & 0x3) << 4),
+            v_sync_width: u32::from(d[10] & 0xF) | (u32::from(d[11] & 0x3) << 4),
+            width_mm: u32::from(d[12]) | (u32::from(d[14] >> 4) << 8),
+            height_mm: u32::from(d[13]) | (u32::from(d[14] & 0xF) << 8),
+            interlaced: (d[17] & 0x80) != 0,
+        }
+    }
+
+    /// Refresh rate in Hz
+    pub fn refresh_rate(&self) -> u32 {
+        let total = (self.h_active + self.h_blank) * (self.v_active + self.v_blank);
+        if total == 0 {
+            0
+        } else {
+            self.pixel_clock.0 / total
+        }
+    }
+}
+
+impl Edid {
+    pub fn parse(block: &[u8; EDID_BLOCK_LEN]) -> Result<Self, Error> {
+        if block[..HEADER.len()] != HEADER {
+            return Err(Error::BadHeader);
+        }
+
+        let sum = block.iter().fold(0_u8, |sum, &b| sum.wrapping_add(b));
+        if sum != 0 {
+            return Err(Error::BadChecksum);
+        }
+
+        if block[18] != 1 {
+            return Err(Error::UnsupportedVersion);
+        }
+
+        let mut edid = Edid {
+            version: block[18],
+            revision: block[19],
+            width_cm: block[21],
+            height_cm: block[22],
+            preferred: None,
+            detailed_timings: Vec::new(),
+            modes: Vec::new(),
+            monitor_name: String::new(),
+        };
+
+        let established =
+            u32::from(block[35]) << 16 | u32::from(block[36]) << 8 | u32::from(block[37]);
+        for (i, &(width, height, refresh_rate)) in ESTABLISHED_MODES.iter().enumerate() {
+            if (established & (1 << (23 - i))) != 0 {
+                edid.push_mode(width, height, refresh_rate);
+            }
+        }
+
+        for st in block[38..54].chunks(2) {
+            // 0x01 0x01 is unused
+            if (st[0] == 0x01 && st[1] == 0x01) || st[0] == 0 {
+                continue;
+            }
+
+            let width = (u32::from(st[0]) + 31) * 8;
+            let height = match st[1] >> 6 {
+                // 1:1 before EDID 1.3
+                0 if edid.revision < 3 => width,
+                0 => width * 10 / 16,
+                1 => width * 3 / 4,
+                2 => width * 4 / 5,
+                _ => width * 9 / 16,
+            };
+            edid.push_mode(width, height, u32::from(st[1] & 0x3F) + 60);
+        }
+
+        for &offset in DESCRIPTORS.iter() {
+            let d = &block[offset..offset + DESCRIPTOR_LEN];
+
+            // Display descriptors have a zero pixel clock
+            if d[0] == 0 && d[1] == 0 {
+                if d[3] == DESCRIPTOR_MONITOR_NAME {
+                    edid.monitor_name.clear();
+                    for &c in d[5..].iter().take_while(|&&c| c != b'\n') {
+                        // Not ASCII, drop it
+                        if c.is_ascii() {
+                            edid.monitor_name.push(c as char).ok();
+                        }
+                    }
+                }
+            } else {
+                let timing = DetailedTiming::parse(d);
+                if edid.preferred.is_none() {
+                    edid.preferred = Some(timing);
+                }
+                edid.detailed_timings.push(timing).ok();
+            }
+        }
+
+        Ok(edid)
+    }
+
+    fn push_mode(&mut self, width: u32, height: u32, refresh_rate: u32) {
+        // At most 17 established and 8 standard timings, always fits
+        self.modes
+            .push(Mode {
+                width,
+                height,
+                refresh_rate,
+            })
+            .ok();
+    }
+}
@@ -8,10 +8,8 @@
 //
 // - Not all of the type states are supported
 //
-// - Would be more efficient to group configs, currently
-// each pin config takes 2x150 wait cycles
-//
-// - GPPUDCLK1 support
+// - Event detect enable registers are shared, enabling/disabling
+// interrupts is a non-atomic read-modify-write
 
 use bcm2837::gpio::*;
 use core::marker::PhantomData;
@@ -64,8 +62,28 @@ pub struct Output<MODE> {
 /// Push pull output (type state)
 pub struct PushPull;
 
+/// Edge that sets the event detect status of an input pin
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Edge {
+    Rising,
+    Falling,
+    Both,
+}
+
+/// Level that sets the event detect status of an input pin
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Level {
+    High,
+    Low,
+}
+
 pub struct Parts {
     /// Pins
+    pub p0: Pin0<Input<Floating>>,
+    pub p1: Pin1<Input<Floating>>,
+    pub p2: Pin2<Input<Floating>>,
+    pub p3: Pin3<Input<Floating>>,
+    pub p4: Pin4<Input<Floating>>,
     pub p5: Pin5<Input<Floating>>,
     pub p6: Pin6<Input<Floating>>,
     pub p7: Pin7<Input<Floating>>,
@@ -73,8 +91,48 @@ pub struct Parts {
     pub p9: Pin9<Input<Floating>>,
     pub p10: Pin10<Input<Floating>>,
     pub p11: Pin11<Input<Floating>>,
+    pub p12: Pin12<Input<Floating>>,
+    pub p13: Pin13<Input<Floating>>,
     pub p14: Pin14<Input<Floating>>,
     pub p15: Pin15<Input<Floating>>,
+    pub p16: Pin16<Input<Floating>>,
+    pub p17: Pin17<Input<Floating>>,
+    pub p18: Pin18<Input<Floating>>,
+    pub p19: Pin19<Input<Floating>>,
+    pub p20: Pin20<Input<Floating>>,
+    pub p21: Pin21<Input<Floating>>,
+    pub p22: Pin22<Input<Floating>>,
+    pub p23: Pin23<Input<Floating>>,
+    pub p24: Pin24<Input<Floating>>,
+    pub p25: Pin25<Input<Floating>>,
+    pub p26: Pin26<Input<Floating>>,
+    pub p27: Pin27<Input<Floating>>,
+    pub p28: Pin28<Input<Floating>>,
+    pub p29: Pin29<Input<Floating>>,
+    pub p30: Pin30<Input<Floating>>,
+    pub p31: Pin31<Input<Floating>>,
+    pub p32: Pin32<Input<Floating>>,
+    pub p33: Pin33<Input<Floating>>,
+    pub p34: Pin34<Input<Floating>>,
+    pub p35: Pin35<Input<Floating>>,
+    pub p36: Pin36<Input<Floating>>,
+    pub p37: Pin37<Input<Floating>>,
+    pub p38: Pin38<Input<Floating>>,
+    pub p39: Pin39<Input<Floating>>,
+    pub p40: Pin40<Input<Floating>>,
+    pub p41: Pin41<Input<Floating>>,
+    pub p42: Pin42<Input<Floating>>,
+    pub p43: Pin43<Input<Floating>>,
+    pub p44: Pin44<Input<Floating>>,
+    pub p45: Pin45<Input<Floating>>,
+    pub p46: Pin46<Input<Floating>>,
+    pub p47: Pin47<Input<Floating>>,
+    pub p48: Pin48<Input<Floating>>,
+    pub p49: Pin49<Input<Floating>>,
+    pub p50: Pin50<Input<Floating>>,
+    pub p51: Pin51<Input<Floating>>,
+    pub p52: Pin52<Input<Floating>>,
+    pub p53: Pin53<Input<Floating>>,
 }
 
 impl GpioExt for GPIO {
@@ -82,52 +140,63 @@ impl GpioExt for GPIO {
 
     fn split(self) -> Parts {
         // Each pin gets a copy of the GPIO vaddr
+        let addr = self.as_ptr() as _;
+
         Parts {
-            p5: Pin5 {
-                pin: 0,
-                addr: self.as_ptr() as _,
-                _mode: PhantomData,
-            },
-            p6: Pin6 {
-                pin: 0,
-                addr: self.as_ptr() as _,
-                _mode: PhantomData,
-            },
-            p7: Pin7 {
-                pin: 0,
-                addr: self.as_ptr() as _,
-                _mode: PhantomData,
-            },
-            p8: Pin8 {
-                pin: 0,
-                addr: self.as_ptr() as _,
-                _mode: PhantomData,
-            },
-            p9: Pin9 {
-                pin: 0,
-                addr: self.as_ptr() as _,
-                _mode: PhantomData,
-            },
-            p10: Pin10 {
-                pin: 0,
-                addr: self.as_ptr() as _,
-                _mode: PhantomData,
-            },
-            p11: Pin11 {
-                pin: 0,
-                addr: self.as_ptr() as _,
-                _mode: PhantomData,
-            },
-            p14: Pin14 {
-                pin: 0,
-                addr: self.as_ptr() as _,
-                _mode: PhantomData,
-            },
-            p15: Pin15 {
-                pin: 0,
-                addr: self.as_ptr() as _,
-                _mode: PhantomData,
-            },
+            p0: Pin0::new(addr),
+            p1: Pin1::new(addr),
+            p2: Pin2::new(addr),
+            p3: Pin3::new(addr),
+            p4: Pin4::new(addr),
+            p5: Pin5::new(addr),
+            p6: Pin6::new(addr),
+            p7: Pin7::new(addr),
+            p8: Pin8::new(addr),
+            p9: Pin9::new(addr),
+            p10: Pin10::new(addr),
+            p11: Pin11::new(addr),
+            p12: Pin12::new(addr),
+            p13: Pin13::new(addr),
+            p14: Pin14::new(addr),
+            p15: Pin15::new(addr),
+            p16: Pin16::new(addr),
+            p17: Pin17::new(addr),
+            p18: Pin18::new(addr),
+            p19: Pin19::new(addr),
+            p20: Pin20::new(addr),
+            p21: Pin21::new(addr),
+            p22: Pin22::new(addr),
+            p23: Pin23::new(addr),
+            p24: Pin24::new(addr),
+            p25: Pin25::new(addr),
+            p26: Pin26::new(addr),
+            p27: Pin27::new(addr),
+            p28: Pin28::new(addr),
+            p29: Pin29::new(addr),
+            p30: Pin30::new(addr),
+            p31: Pin31::new(addr),
+            p32: Pin32::new(addr),
+            p33: Pin33::new(addr),
+            p34: Pin34::new(addr),
+            p35: Pin35::new(addr),
+            p36: Pin36::new(addr),
+            p37: Pin37::new(addr),
+            p38: Pin38::new(addr),
+            p39: Pin39::new(addr),
+            p40: Pin40::new(addr),
+            p41: Pin41::new(addr),
+            p42: Pin42::new(addr),
+            p43: Pin43::new(addr),
+            p44: Pin44::new(addr),
+            p45: Pin45::new(addr),
+            p46: Pin46::new(addr),
+            p47: Pin47::new(addr),
+            p48: Pin48::new(addr),
+            p49: Pin49::new(addr),
+            p50: Pin50::new(addr),
+            p51: Pin51::new(addr),
+            p52: Pin52::new(addr),
+            p53: Pin53::new(addr),
         }
     }
 }
@@ -135,17 +204,139 @@ impl GpioExt for GPIO {
 /// GPIO pull-up/down clock sequence wait cycles
 const WAIT_CYCLES: usize = 150;
 
+/// Number of GPFSELn registers
+const NUM_FSEL_REGS: usize = 6;
+
+/// Number of GPPUDCLKn registers
+const NUM_PUDCLK_REGS: usize = 2;
+
+/// Pull-up/down control, index into the batched clock bits
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Pull {
+    Off = 0,
+    Down = 1,
+    Up = 2,
+}
+
+/// Collects function select and pull-up/down changes for many pins and
+/// applies them with one GPFSELn read-modify-write per register and one
+/// pull-up/down clock sequence per pull mode, instead of 2x150 wait cycles
+/// per pin
+///
+/// Only created by `configure()`, which always applies the batch, so the
+/// pins returned by the `*_batched()` methods are configured by the time
+/// they can be used
+pub struct GpioConfigBatch {
+    gpio: GPIO,
+    /// (mask, value) per GPFSELn register
+    fsel: [(u32, u32); NUM_FSEL_REGS],
+    /// GPPUDCLK0/GPPUDCLK1 bits per pull mode
+    pud_clk: [[u32; NUM_PUDCLK_REGS]; 3],
+}
+
+impl GpioConfigBatch {
+    fn new(gpio: GPIO) -> Self {
+        GpioConfigBatch {
+            gpio,
+            fsel: [(0, 0); NUM_FSEL_REGS],
+            pud_clk: [[0; NUM_PUDCLK_REGS]; 3],
+        }
+    }
+
+    /// Collects the pin configurations made in `f` and applies them
+    pub fn configure<F, R>(gpio: GPIO, f: F) -> R
+    where
+        F: FnOnce(&mut GpioConfigBatch) -> R,
+    {
+        let mut batch = GpioConfigBatch::new(gpio);
+        let pins = f(&mut batch);
+        batch.apply();
+        pins
+    }
+
+    /// Writes the collected configurations to the hardware
+    fn apply(self) {
+        for (reg, &(mask, value)) in self.fsel.iter().enumerate() {
+            if mask != 0 {
+                self.modify_fsel(reg, mask, value);
+            }
+        }
+
+        for (pull, clk) in [Pull::Off, Pull::Down, Pull::Up]
+            .iter()
+            .zip(self.pud_clk.iter())
+        {
+            if (clk[0] == 0) && (clk[1] == 0) {
+                continue;
+            }
+
+            match pull {
+                Pull::Off => self.gpio.GPPUD.write(GPPUD::PUD::Off),
+                Pull::Down => self.gpio.GPPUD.write(GPPUD::PUD::PullDown),
+                Pull::Up => self.gpio.GPPUD.write(GPPUD::PUD::PullUp),
+            }
+
+            for _ in 0..WAIT_CYCLES {
+                asm::nop();
+            }
+            self.gpio.GPPUDCLK0.set(clk[0]);
+            self.gpio.GPPUDCLK1.set(clk[1]);
+            for _ in 0..WAIT_CYCLES {
+                asm::nop();
+            }
+            self.gpio.GPPUDCLK0.set(0);
+            self.gpio.GPPUDCLK1.set(0);
+        }
+    }
+
+    fn push(&mut self, pin: u32, fsel_mask: u32, fsel_value: u32, pull: Pull) {
+        let fsel = &mut self.fsel[(pin / 10) as usize];
+        fsel.0 |= fsel_mask;
+        fsel.1 = (fsel.1 & !fsel_mask) | fsel_value;
+
+        // A pin is only clocked for its most recent pull mode
+        let bank = (pin / 32) as usize;
+        let bit = 1 << (pin % 32);
+        for clk in self.pud_clk.iter_mut() {
+            clk[bank] &= !bit;
+        }
+        self.pud_clk[pull as usize][bank] |= bit;
+    }
+
+    fn modify_fsel(&self, reg: usize, mask: u32, value: u32) {
+        let gpio = &self.gpio;
+
+        match reg {
+            0 => gpio.GPFSEL0.set((gpio.GPFSEL0.get() & !mask) | value),
+            1 => gpio.GPFSEL1.set((gpio.GPFSEL1.get() & !mask) | value),
+            2 => gpio.GPFSEL2.set((gpio.GPFSEL2.get() & !mask) | value),
+            3 => gpio.GPFSEL3.set((gpio.GPFSEL3.get() & !mask) | value),
+            4 => gpio.GPFSEL4.set((gpio.GPFSEL4.get() & !mask) | value),
+            5 => gpio.GPFSEL5.set((gpio.GPFSEL5.get() & !mask) | value),
+            _ => unreachable!(),
+        }
+    }
+}
+
 macro_rules! gpio {
-    ($GPFSELn:ident, $GPPUDCLKx:ident, $GPLEVx:ident, $GPSETx:ident, $GPCLRx:ident, [
-        $($PXi:ident: ($pxi:ident, $FSELi:ident, $PUDCLKi:ident, $MODE:ty),)+
+    ($GPFSELn:ident, $GPPUDCLKx:ident, $GPLEVx:ident, $GPSETx:ident, $GPCLRx:ident,
+     $GPEDSx:ident, $GPRENx:ident, $GPFENx:ident, $GPHENx:ident, $GPLENx:ident, [
+        $($PXi:ident: ($pxi:ident, $i:expr, $FSELi:ident, $PUDCLKi:ident, $MODE:ty),)+
     ]) => {
 $(
 pub struct $PXi<MODE> {
+    /// Bit position within the GPSETn/GPCLRn/GPLEVn bank
     pin: u32,
     addr: *const u64,
     _mode: PhantomData<MODE>,
 }
 
+impl $PXi<$MODE> {
+    fn new(addr: *const u64) -> Self {
+        $PXi { pin: $i % 32, addr, _mode: PhantomData }
+    }
+}
+
 impl<MODE> Deref for $PXi<MODE> {
     type Target = RegisterBlock;
     fn deref(&self) -> &Self::Target {
@@ -303,6 +494,86 @@ impl<MODE> $PXi<MODE> {
 
         $PXi { pin: self.pin, addr: self.addr, _mode: PhantomData }
     }
+
+    /// Batched `into_alternate_af0()`, applied by `GpioConfigBatch::configure()`
+    pub fn into_alternate_af0_batched(self, batch: &mut GpioConfigBatch) -> $PXi<Alternate<AF0>> {
+        let fsel = $GPFSELn::$FSELi::AF0;
+        batch.push($i, fsel.mask, fsel.value, Pull::Off);
+
+        $PXi { pin: self.pin, addr: self.addr, _mode: PhantomData }
+    }
+
+    /// Batched `into_alternate_af1()`, applied by `GpioConfigBatch::configure()`
+    pub fn into_alternate_af1_batched(self, batch: &mut GpioConfigBatch) -> $PXi<Alternate<AF1>> {
+        let fsel = $GPFSELn::$FSELi::AF1;
+        batch.push($i, fsel.mask, fsel.value, Pull::Off);
+
+        $PXi { pin: self.pin, addr: self.addr, _mode: PhantomData }
+    }
+
+    /// Batched `into_alternate_af2()`, applied by `GpioConfigBatch::configure()`
+    pub fn into_alternate_af2_batched(self, batch: &mut GpioConfigBatch) -> $PXi<Alternate<AF2>> {
+        let fsel = $GPFSELn::$FSELi::AF2;
+        batch.push($i, fsel.mask, fsel.value, Pull::Off);
+
+        $PXi { pin: self.pin, addr: self.addr, _mode: PhantomData }
+    }
+
+    /// Batched `into_alternate_af3()`, applied by `GpioConfigBatch::configure()`
+    pub fn into_alternate_af3_batched(self, batch: &mut GpioConfigBatch) -> $PXi<Alternate<AF3>> {
+        let fsel = $GPFSELn::$FSELi::AF3;
+        batch.push($i, fsel.mask, fsel.value, Pull::Off);
+
+        $PXi { pin: self.pin, addr: self.addr, _mode: PhantomData }
+    }
+
+    /// Batched `into_alternate_af4()`, applied by `GpioConfigBatch::configure()`
+    pub fn into_alternate_af4_batched(self, batch: &mut GpioConfigBatch) -> $PXi<Alternate<AF4>> {
+        let fsel = $GPFSELn::$FSELi::AF4;
+        batch.push($i, fsel.mask, fsel.value, Pull::Off);
+
+        $PXi { pin: self.pin, addr: self.addr, _mode: PhantomData }
+    }
+
+    /// Batched `into_alternate_af5()`, applied by `GpioConfigBatch::configure()`
+    pub fn into_alternate_af5_batched(self, batch: &mut GpioConfigBatch) -> $PXi<Alternate<AF5>> {
+        let fsel = $GPFSELn::$FSELi::AF5;
+        batch.push($i, fsel.mask, fsel.value, Pull::Off);
+
+        $PXi { pin: self.pin, addr: self.addr, _mode: PhantomData }
+    }
+
+    /// Batched `into_floating_input()`, applied by `GpioConfigBatch::configure()`
+    pub fn into_floating_input_batched(self, batch: &mut GpioConfigBatch) -> $PXi<Input<Floating>> {
+        let fsel = $GPFSELn::$FSELi::Input;
+        batch.push($i, fsel.mask, fsel.value, Pull::Off);
+
+        $PXi { pin: self.pin, addr: self.addr, _mode: PhantomData }
+    }
+
+    /// Batched `into_pull_down_input()`, applied by `GpioConfigBatch::configure()`
+    pub fn into_pull_down_input_batched(self, batch: &mut GpioConfigBatch) -> $PXi<Input<PullDown>> {
+        let fsel = $GPFSELn::$FSELi::Input;
+        batch.push($i, fsel.mask, fsel.value, Pull::Down);
+
+        $PXi { pin: self.pin, addr: self.addr, _mode: PhantomData }
+    }
+
+    /// Batched `into_pull_up_input()`, applied by `GpioConfigBatch::configure()`
+    pub fn into_pull_up_input_batched(self, batch: &mut GpioConfigBatch) -> $PXi<Input<PullUp>> {
+        let fsel = $GPFSELn::$FSELi::Input;
+        batch.push($i, fsel.mask, fsel.value, Pull::Up);
+
+        $PXi { pin: self.pin, addr: self.addr, _mode: PhantomData }
+    }
+
+    /// Batched `into_push_pull_output()`, applied by `GpioConfigBatch::configure()`
+    pub fn into_push_pull_output_batched(self, batch: &mut GpioConfigBatch) -> $PXi<Output<PushPull>> {
+        let fsel = $GPFSELn::$FSELi::Output;
+        batch.push($i, fsel.mask, fsel.value, Pull::Off);
+
+        $PXi { pin: self.pin, addr: self.addr, _mode: PhantomData }
+    }
 }
 
 impl<MODE> OutputPin for $PXi<Output<MODE>> {
@@ -325,6 +596,66 @@ impl<MODE> StatefulOutputPin for $PXi<Output<MODE>> {
     }
 }
 
+impl<MODE> $PXi<Input<MODE>> {
+    /// Enables edge event detection, the GPIO IRQ is raised while the
+    /// event detect status bit is set
+    pub fn enable_interrupt(&mut self, edge: Edge) {
+        let mask = 1 << self.pin;
+
+        match edge {
+            Edge::Rising => {
+                self.$GPRENx.set(self.$GPRENx.get() | mask);
+                self.$GPFENx.set(self.$GPFENx.get() & !mask);
+            }
+            Edge::Falling => {
+                self.$GPRENx.set(self.$GPRENx.get() & !mask);
+                self.$GPFENx.set(self.$GPFENx.get() | mask);
+            }
+            Edge::Both => {
+                self.$GPRENx.set(self.$GPRENx.get() | mask);
+                self.$GPFENx.set(self.$GPFENx.get() | mask);
+            }
+        }
+    }
+
+    /// Enables level event detection, the event detect status stays set
+    /// while the pin is at the given level
+    pub fn enable_level_interrupt(&mut self, level: Level) {
+        let mask = 1 << self.pin;
+
+        match level {
+            Level::High => {
+                self.$GPHENx.set(self.$GPHENx.get() | mask);
+                self.$GPLENx.set(self.$GPLENx.get() & !mask);
+            }
+            Level::Low => {
+                self.$GPHENx.set(self.$GPHENx.get() & !mask);
+                self.$GPLENx.set(self.$GPLENx.get() | mask);
+            }
+        }
+    }
+
+    /// Disables all edge and level event detection
+    pub fn disable_interrupt(&mut self) {
+        let mask = !(1 << self.pin);
+
+        self.$GPRENx.set(self.$GPRENx.get() & mask);
+        self.$GPFENx.set(self.$GPFENx.get() & mask);
+        self.$GPHENx.set(self.$GPHENx.get() & mask);
+        self.$GPLENx.set(self.$GPLENx.get() & mask);
+    }
+
+    /// Returns true if an event was detected on this pin
+    pub fn check_interrupt(&self) -> bool {
+        self.$GPEDSx.get() & (1 << self.pin) != 0
+    }
+
+    /// Clears the event detect status, write 1 to clear
+    pub fn clear_interrupt_pending_bit(&mut self) {
+        self.$GPEDSx.set(1 << self.pin);
+    }
+}
+
 impl<MODE> InputPin for $PXi<Input<MODE>> {
     fn is_high(&self) -> bool {
         !self.is_low()
@@ -344,12 +675,22 @@ gpio!(
     GPLEV0,
     GPSET0,
     GPCLR0,
+    GPEDS0,
+    GPREN0,
+    GPFEN0,
+    GPHEN0,
+    GPLEN0,
     [
-        Pin5: (p5, FSEL5, PUDCLK5, Input<Floating>),
-        Pin6: (p6, FSEL6, PUDCLK6, Input<Floating>),
-        Pin7: (p7, FSEL7, PUDCLK7, Input<Floating>),
-        Pin8: (p8, FSEL8, PUDCLK8, Input<Floating>),
-        Pin9: (p9, FSEL9, PUDCLK9, Input<Floating>),
+        Pin0: (p0, 0, FSEL0, PUDCLK0, Input<Floating>),
+        Pin1: (p1, 1, FSEL1, PUDCLK1, Input<Floating>),
+        Pin2: (p2, 2, FSEL2, PUDCLK2, Input<Floating>),
+        Pin3: (p3, 3, FSEL3, PUDCLK3, Input<Floating>),
+        Pin4: (p4, 4, FSEL4, PUDCLK4, Input<Floating>),
+        Pin5: (p5, 5, FSEL5, PUDCLK5, Input<Floating>),
+        Pin6: (p6, 6, FSEL6, PUDCLK6, Input<Floating>),
+        Pin7: (p7, 7, FSEL7, PUDCLK7, Input<Floating>),
+        Pin8: (p8, 8, FSEL8, PUDCLK8, Input<Floating>),
+        Pin9: (p9, 9, FSEL9, PUDCLK9, Input<Floating>),
     ]
 );
 
@@ -359,10 +700,130 @@ gpio!(
     GPLEV0,
     GPSET0,
     GPCLR0,
+    GPEDS0,
+    GPREN0,
+    GPFEN0,
+    GPHEN0,
+    GPLEN0,
+    [
+        Pin10: (p10, 10, FSEL10, PUDCLK10, Input<Floating>),
+        Pin11: (p11, 11, FSEL11, PUDCLK11, Input<Floating>),
+        Pin12: (p12, 12, FSEL12, PUDCLK12, Input<Floating>),
+        Pin13: (p13, 13, FSEL13, PUDCLK13, Input<Floating>),
+        Pin14: (p14, 14, FSEL14, PUDCLK14, Input<Floating>),
+        Pin15: (p15, 15, FSEL15, PUDCLK15, Input<Floating>),
+        Pin16: (p16, 16, FSEL16, PUDCLK16, Input<Floating>),
+        Pin17: (p17, 17, FSEL17, PUDCLK17, Input<Floating>),
+        Pin18: (p18, 18, FSEL18, PUDCLK18, Input<Floating>),
+        Pin19: (p19, 19, FSEL19, PUDCLK19, Input<Floating>),
+    ]
+);
+
+gpio!(
+    GPFSEL2,
+    GPPUDCLK0,
+    GPLEV0,
+    GPSET0,
+    GPCLR0,
+    GPEDS0,
+    GPREN0,
+    GPFEN0,
+    GPHEN0,
+    GPLEN0,
+    [
+        Pin20: (p20, 20, FSEL20, PUDCLK20, Input<Floating>),
+        Pin21: (p21, 21, FSEL21, PUDCLK21, Input<Floating>),
+        Pin22: (p22, 22, FSEL22, PUDCLK22, Input<Floating>),
+        Pin23: (p23, 23, FSEL23, PUDCLK23, Input<Floating>),
+        Pin24: (p24, 24, FSEL24, PUDCLK24, Input<Floating>),
+        Pin25: (p25, 25, FSEL25, PUDCLK25, Input<Floating>),
+        Pin26: (p26, 26, FSEL26, PUDCLK26, Input<Floating>),
+        Pin27: (p27, 27, FSEL27, PUDCLK27, Input<Floating>),
+        Pin28: (p28, 28, FSEL28, PUDCLK28, Input<Floating>),
+        Pin29: (p29, 29, FSEL29, PUDCLK29, Input<Floating>),
+    ]
+);
+
+gpio!(
+    GPFSEL3,
+    GPPUDCLK0,
+    GPLEV0,
+    GPSET0,
+    GPCLR0,
+    GPEDS0,
+    GPREN0,
+    GPFEN0,
+    GPHEN0,
+    GPLEN0,
+    [
+        Pin30: (p30, 30, FSEL30, PUDCLK30, Input<Floating>),
+        Pin31: (p31, 31, FSEL31, PUDCLK31, Input<Floating>),
+    ]
+);
+
+gpio!(
+    GPFSEL3,
+    GPPUDCLK1,
+    GPLEV1,
+    GPSET1,
+    GPCLR1,
+    GPEDS1,
+    GPREN1,
+    GPFEN1,
+    GPHEN1,
+    GPLEN1,
+    [
+        Pin32: (p32, 32, FSEL32, PUDCLK32, Input<Floating>),
+        Pin33: (p33, 33, FSEL33, PUDCLK33, Input<Floating>),
+        Pin34: (p34, 34, FSEL34, PUDCLK34, Input<Floating>),
+        Pin35: (p35, 35, FSEL35, PUDCLK35, Input<Floating>),
+        Pin36: (p36, 36, FSEL36, PUDCLK36, Input<Floating>),
+        Pin37: (p37, 37, FSEL37, PUDCLK37, Input<Floating>),
+        Pin38: (p38, 38, FSEL38, PUDCLK38, Input<Floating>),
+        Pin39: (p39, 39, FSEL39, PUDCLK39, Input<Floating>),
+    ]
+);
+
+gpio!(
+    GPFSEL4,
+    GPPUDCLK1,
+    GPLEV1,
+    GPSET1,
+    GPCLR1,
+    GPEDS1,
+    GPREN1,
+    GPFEN1,
+    GPHEN1,
+    GPLEN1,
+    [
+        Pin40: (p40, 40, FSEL40, PUDCLK40, Input<Floating>),
+        Pin41: (p41, 41, FSEL41, PUDCLK41, Input<Floating>),
+        Pin42: (p42, 42, FSEL42, PUDCLK42, Input<Floating>),
+        Pin43: (p43, 43, FSEL43, PUDCLK43, Input<Floating>),
+        Pin44: (p44, 44, FSEL44, PUDCLK44, Input<Floating>),
+        Pin45: (p45, 45, FSEL45, PUDCLK45, Input<Floating>),
+        Pin46: (p46, 46, FSEL46, PUDCLK46, Input<Floating>),
+        Pin47: (p47, 47, FSEL47, PUDCLK47, Input<Floating>),
+        Pin48: (p48, 48, FSEL48, PUDCLK48, Input<Floating>),
+        Pin49: (p49, 49, FSEL49, PUDCLK49, Input<Floating>),
+    ]
+);
+
+gpio!(
+    GPFSEL5,
+    GPPUDCLK1,
+    GPLEV1,
+    GPSET1,
+    GPCLR1,
+    GPEDS1,
+    GPREN1,
+    GPFEN1,
+    GPHEN1,
+    GPLEN1,
     [
-        Pin10: (p10, FSEL10, PUDCLK10, Input<Floating>),
-        Pin11: (p11, FSEL11, PUDCLK11, Input<Floating>),
-        Pin14: (p14, FSEL14, PUDCLK14, Input<Floating>),
-        Pin15: (p15, FSEL15, PUDCLK15, Input<Floating>),
+        Pin50: (p50, 50, FSEL50, PUDCLK50, Input<Floating>),
+        Pin51: (p51, 51, FSEL51, PUDCLK51, Input<Floating>),
+        Pin52: (p52, 52, FSEL52, PUDCLK52, Input<Floating>),
+        Pin53: (p53, 53, FSEL53, PUDCLK53, Input<Floating>),
     ]
 );
@@ -3,6 +3,7 @@
 
 extern crate cortex_a;
 extern crate embedded_hal as hal;
+extern crate heapless;
 #[macro_use]
 extern crate nb;
 extern crate void;
@@ -13,9 +14,13 @@ pub mod cache;
 pub mod clocks;
 pub mod delay;
 pub mod dma;
+pub mod edid;
 pub mod gpio;
 pub mod mailbox;
 pub mod mailbox_msg;
 pub mod pmem;
 pub mod serial;
+pub mod spi;
+pub mod thermal;
 pub mod time;
+pub mod timer;
@@ -1,12 +1,16 @@
 // TODO - need to figure out proper compiler_fence/dmb sync
-// TODO - try out core::slice::from_raw_parts_mut()
 
 use bcm2837::mbox::{MBOX, STATUS};
 use core::sync::atomic::{compiler_fence, Ordering};
-use cortex_a::{asm, barrier};
+use cortex_a::barrier;
 
-use mailbox_msg::{MailboxMsgBufferConstructor, Resp, MAILBOX_BUFFER_LEN};
+use mailbox_msg::{
+    LegacyFramebufferCmd, LegacyFramebufferResp, MailboxMsgBufferConstructor, PropertyMessage,
+    PropertyResponse, Resp, Tag,
+};
 use pmem::PMem;
+use time::{Duration, Instant};
+use timer::MonoTimer;
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Error {
@@ -14,6 +18,30 @@ pub enum Error {
     BadRequest,
     /// Status word was not recognized
     BadStatusWord,
+    /// The message doesn't fit in the mailbox buffer
+    BufferTooSmall,
+    /// The response bit of the tag is not set
+    TagNotAcknowledged(Tag),
+    /// The response, or a tag value, is shorter than expected
+    TruncatedResponse,
+    /// The tag is not in the response
+    MissingTag(Tag),
+    /// The tag value is not supported
+    InvalidResponse(Tag),
+    /// The request values are out of range for the tag
+    InvalidRequest(Tag),
+    /// The firmware didn't respond in time
+    Timeout,
+    /// A submitted call is still waiting for its response
+    Busy,
+    /// No call was submitted
+    NotSubmitted,
+    /// Property messages can't be sent on the channel
+    InvalidChannel,
+    /// The call needs a timer, see `Mailbox::with_timer`
+    NoTimer,
+    /// The tag has no `Resp` variant, use `call_batch` for its response
+    BatchOnly(Tag),
     /// Unknown error
     Unknown,
     #[doc(hidden)]
@@ -22,8 +50,25 @@ pub enum Error {
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Channel {
-    /// Property channel
+    /// Power management
+    Power,
+    /// Framebuffer, superseded by the property channel
+    Framebuffer,
+    /// Virtual UART
+    VirtualUart,
+    /// VCHIQ
+    Vchiq,
+    /// LEDs
+    Leds,
+    /// Buttons
+    Buttons,
+    /// Touch screen
+    Touchscreen,
+    /// Property channel, ARM to VideoCore
     Prop,
+    /// Property channel, VideoCore to ARM, only usable with
+    /// `send_raw`/`receive_raw`
+    VcProp,
     #[doc(hidden)]
     _Extensible,
 }
@@ -35,32 +80,146 @@ mod response_status {
 }
 
 /// Mailbox abstraction
+#[derive(Debug)]
 pub struct Mailbox {
     mbox: MBOX,
     buffer_pmem: PMem,
-}
-
-#[repr(C)]
-#[repr(align(16))]
-struct MailboxBuffer {
-    pub data: [u32; MAILBOX_BUFFER_LEN],
+    timer: Option<MonoTimer>,
+    /// Channel of the call waiting for a response
+    pending: Option<Channel>,
+    /// Channel of a timed out call, its late response is discarded
+    stale: Option<Channel>,
 }
 
 impl Mailbox {
     pub fn new(mbox: MBOX, buffer_pmem: PMem) -> Self {
-        Self { mbox, buffer_pmem }
+        Self {
+            mbox,
+            buffer_pmem,
+            timer: None,
+            pending: None,
+            stale: None,
+        }
     }
 
-    /// Make a mailbox call. Returns Err(MboxError) on failure, Ok(()) success
+    /// Mailbox with a time source, required by `call_with_timeout`
+    pub fn with_timer(mbox: MBOX, buffer_pmem: PMem, timer: MonoTimer) -> Self {
+        Self {
+            mbox,
+            buffer_pmem,
+            timer: Some(timer),
+            pending: None,
+            stale: None,
+        }
+    }
+
+    /// Make a mailbox call, returns the parsed response to the first tag
+    /// once every tag in the response is validated
     pub fn call<T: MailboxMsgBufferConstructor>(
         &mut self,
         channel: Channel,
         constructor: &T,
     ) -> Result<Resp, Error> {
-        let buffer = self.buffer_pmem.as_mut_ptr::<MailboxBuffer>();
+        let resp = self.call_batch(channel, constructor)?;
+        Resp::parse(&resp)
+    }
+
+    /// Make a mailbox call with any number of tags, the response tags
+    /// are parsed individually from the returned `PropertyResponse`
+    pub fn call_batch<T: MailboxMsgBufferConstructor>(
+        &mut self,
+        channel: Channel,
+        constructor: &T,
+    ) -> Result<PropertyResponse, Error> {
+        block!(self.submit(channel, constructor))?;
+        block!(self.poll_response())?;
+        self.response()
+    }
+
+    /// Like `call`, but gives up with `Error::Timeout` if the firmware
+    /// doesn't respond in time
+    ///
+    /// The firmware may still respond late, the next `submit` waits for
+    /// that response and discards it before reusing the buffer
+    pub fn call_with_timeout<T: MailboxMsgBufferConstructor>(
+        &mut self,
+        channel: Channel,
+        constructor: &T,
+        timeout: Duration,
+    ) -> Result<Resp, Error> {
+        let timer = self.timer.ok_or(Error::NoTimer)?;
+        let deadline = timer
+            .now()
+            .checked_add(timeout)
+            .unwrap_or_else(|| Instant::from_micros(u64::max_value()));
+
+        loop {
+            match self.submit(channel, constructor) {
+                Ok(()) => break,
+                Err(nb::Error::Other(e)) => return Err(e),
+                Err(nb::Error::WouldBlock) => (),
+            }
+
+            if timer.now() >= deadline {
+                return Err(Error::Timeout);
+            }
+        }
+
+        loop {
+            match self.poll_response() {
+                Ok(()) => break,
+                Err(nb::Error::Other(e)) => return Err(e),
+                Err(nb::Error::WouldBlock) => (),
+            }
 
-        unsafe {
-            constructor.construct_buffer(&mut (*buffer).data);
+            if timer.now() >= deadline {
+                self.stale = self.pending.take();
+                return Err(Error::Timeout);
+            }
+        }
+
+        let resp = self.response()?;
+        Resp::parse(&resp)
+    }
+
+    /// Writes the message to the buffer and signals the firmware, the
+    /// response is then collected with `poll` or `poll_batch`
+    ///
+    /// Returns `WouldBlock` while the mailbox is full, or while the late
+    /// response to a timed out call hasn't been drained
+    pub fn submit<T: MailboxMsgBufferConstructor>(
+        &mut self,
+        channel: Channel,
+        constructor: &T,
+    ) -> nb::Result<(), Error> {
+        // The firmware only answers ARM requests on the ARM to VideoCore
+        // property channel
+        if channel != Channel::Prop {
+            return Err(nb::Error::Other(Error::InvalidChannel));
+        }
+
+        if self.pending.is_some() {
+            return Err(nb::Error::Other(Error::Busy));
+        }
+
+        if self.stale.is_some() {
+            self.read_message();
+            if self.stale.is_some() {
+                return Err(nb::Error::WouldBlock);
+            }
+        }
+
+        if self.mbox.STATUS.is_set(STATUS::FULL) {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        {
+            let buffer = self
+                .buffer_pmem
+                .as_mut_slice::<u32>(self.buffer_pmem.size() / 4);
+            let mut msg = PropertyMessage::new(buffer)?;
+            constructor.construct_buffer(&mut msg)?;
+            msg.finish();
         }
 
         // Insert a compiler fence that ensures that all stores to the
@@ -71,15 +230,6 @@ impl Mailbox {
         // TODO - wmb() ?
         //unsafe { asm!("dmb st" : : : "memory") };
 
-        // wait until we can write to the mailbox
-        loop {
-            if !self.mbox.STATUS.is_set(STATUS::FULL) {
-                break;
-            }
-
-            asm::nop();
-        }
-
         let buf_ptr = self.buffer_pmem.paddr();
 
         // write the address of our message to the mailbox with channel identifier
@@ -87,46 +237,186 @@ impl Mailbox {
             .WRITE
             .set((buf_ptr & !0xF) | (u32::from(channel) & 0xF));
 
-        // now wait for the response
-        loop {
-            // is there a response?
-            loop {
-                if !self.mbox.STATUS.is_set(STATUS::EMPTY) {
-                    break;
-                }
+        self.pending = Some(channel);
 
-                asm::nop();
-            }
+        Ok(())
+    }
+
+    /// Returns the parsed response to the first tag of the submitted call
+    pub fn poll(&mut self) -> nb::Result<Resp, Error> {
+        self.poll_response()?;
+        let resp = self.response()?;
+        Ok(Resp::parse(&resp)?)
+    }
+
+    /// Returns the response to the submitted call
+    pub fn poll_batch(&mut self) -> nb::Result<PropertyResponse, Error> {
+        self.poll_response()?;
+        Ok(self.response()?)
+    }
+
+    /// Waits for the response to the pending call, responses meant for
+    /// other buffers or channels are discarded
+    fn poll_response(&mut self) -> nb::Result<(), Error> {
+        let channel = match self.pending {
+            Some(c) => c,
+            None => return Err(nb::Error::Other(Error::NotSubmitted)),
+        };
+
+        // is there a response?
+        let resp = match self.read_message() {
+            Some(resp) => resp,
+            None => return Err(nb::Error::WouldBlock),
+        };
+
+        // is it a response to our message?
+        if !self.is_response(resp, channel) {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        self.pending = None;
+
+        Ok(())
+    }
+
+    /// Writes 28 bits of data to the channel, the lower 4 bits of `data`
+    /// are replaced by the channel number
+    ///
+    /// Returns `WouldBlock` while the mailbox is full
+    pub fn send_raw(&mut self, channel: Channel, data: u32) -> nb::Result<(), Error> {
+        if self.mbox.STATUS.is_set(STATUS::FULL) {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        self.mbox
+            .WRITE
+            .set((data & !0xF) | (u32::from(channel) & 0xF));
+
+        Ok(())
+    }
+
+    /// Reads 28 bits of data from the channel, the lower 4 bits of the
+    /// returned value are cleared
+    ///
+    /// Messages for other channels are discarded, so this can't be used
+    /// while a property call is pending
+    pub fn receive_raw(&mut self, channel: Channel) -> nb::Result<u32, Error> {
+        if self.pending.is_some() {
+            return Err(nb::Error::Other(Error::Busy));
+        }
+
+        let data = match self.read_message() {
+            Some(data) => data,
+            None => return Err(nb::Error::WouldBlock),
+        };
+
+        if (data & 0xF) != channel.into() {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        Ok(data & !0xF)
+    }
+
+    /// Allocates a framebuffer through the framebuffer channel, for
+    /// comparison with `FramebufferCmd` on the property channel
+    pub fn call_legacy_framebuffer(
+        &mut self,
+        cmd: &LegacyFramebufferCmd,
+    ) -> Result<LegacyFramebufferResp, Error> {
+        // A late response could overwrite the buffer
+        if self.pending.is_some() || self.stale.is_some() {
+            return Err(Error::Busy);
+        }
+
+        {
+            let buffer = self
+                .buffer_pmem
+                .as_mut_slice::<u32>(self.buffer_pmem.size() / 4);
+            cmd.construct_buffer(buffer)?;
+        }
+
+        compiler_fence(Ordering::Release);
+
+        let buf_ptr = self.buffer_pmem.paddr();
+        block!(self.send_raw(Channel::Framebuffer, buf_ptr))?;
+
+        // Zero on success
+        let status = block!(self.receive_raw(Channel::Framebuffer))?;
+
+        unsafe { barrier::dmb(barrier::SY) };
+
+        if status != 0 {
+            return Err(Error::BadRequest);
+        }
+
+        LegacyFramebufferResp::parse(
+            self.buffer_pmem
+                .as_slice::<u32>(self.buffer_pmem.size() / 4),
+        )
+    }
 
-            let resp: u32 = self.mbox.READ.get();
-
-            // is it a response to our message?
-            if ((resp & 0xF) == channel.into()) && ((resp & !0xF) == buf_ptr) {
-                // TODO - rmb() ?
-                //unsafe { asm!("dmb ld" : : : "memory") };
-                //compiler_fence(Ordering::Release);
-                unsafe { barrier::dmb(barrier::SY) };
-
-                let status = unsafe { (*buffer).data[1] };
-                //let status: u32 =
-                //    unsafe { ::core::ptr::read_volatile((self.buffer as *mut u32).offset(1))
-                // };
-
-                // is it a valid successful response?
-                return match status {
-                    response_status::SUCCESS => Ok(Resp::from(unsafe { &(*buffer).data })),
-                    response_status::ERROR => Err(Error::BadRequest),
-                    _ => Err(Error::BadStatusWord),
-                };
+    /// Releases the mailbox peripheral and buffer, a pending call is dropped
+    pub fn free(self) -> (MBOX, PMem) {
+        (self.mbox, self.buffer_pmem)
+    }
+
+    /// Reads the next message, the late response to a timed out call
+    /// is discarded
+    fn read_message(&mut self) -> Option<u32> {
+        if self.mbox.STATUS.is_set(STATUS::EMPTY) {
+            return None;
+        }
+
+        let data: u32 = self.mbox.READ.get();
+
+        if let Some(channel) = self.stale {
+            if self.is_response(data, channel) {
+                self.stale = None;
+                return None;
             }
         }
+
+        Some(data)
+    }
+
+    /// Is the message a response to our buffer on the channel
+    fn is_response(&self, data: u32, channel: Channel) -> bool {
+        ((data & 0xF) == channel.into()) && ((data & !0xF) == self.buffer_pmem.paddr())
+    }
+
+    /// Reads the response from the buffer
+    fn response(&self) -> Result<PropertyResponse, Error> {
+        // TODO - rmb() ?
+        //unsafe { asm!("dmb ld" : : : "memory") };
+        //compiler_fence(Ordering::Release);
+        unsafe { barrier::dmb(barrier::SY) };
+
+        let buffer = self
+            .buffer_pmem
+            .as_slice::<u32>(self.buffer_pmem.size() / 4);
+        let status = buffer[1];
+
+        // is it a valid successful response?
+        match status {
+            response_status::SUCCESS => PropertyResponse::new(buffer),
+            response_status::ERROR => Err(Error::BadRequest),
+            _ => Err(Error::BadStatusWord),
+        }
     }
 }
 
 impl From<Channel> for u32 {
     fn from(c: Channel) -> u32 {
         match c {
+            Channel::Power => 0,
+            Channel::Framebuffer => 1,
+            Channel::VirtualUart => 2,
+            Channel::Vchiq => 3,
+            Channel::Leds => 4,
+            Channel::Buttons => 5,
+            Channel::Touchscreen => 6,
             Channel::Prop => 8,
+            Channel::VcProp => 9,
             _ => unimplemented!(),
         }
     }
@@ -1,10 +1,11 @@
+use mailbox::Error;
+
 use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
 use super::Tag;
-use super::MAILBOX_BUFFER_LEN;
-use super::REQUEST;
 
-pub const CMD_LEN: u32 = 8;
-pub const RESP_LEN: u32 = 8;
+pub const RESP_LEN: usize = 8;
 
 #[derive(Debug, Copy, Clone)]
 pub struct BlankScreenCmd {
@@ -17,25 +18,22 @@ pub struct BlankScreenResp {
 }
 
 impl MailboxMsgBufferConstructor for BlankScreenCmd {
-    fn construct_buffer(&self, buffer: &mut [u32; MAILBOX_BUFFER_LEN]) {
-        buffer[0] = 8 * 4;
-        buffer[1] = REQUEST;
-        buffer[2] = Tag::BlankScreen.into();
-        buffer[3] = CMD_LEN;
-        buffer[4] = RESP_LEN;
-        buffer[5] = if self.power_down { 1 } else { 0 };
-        buffer[6] = 0;
-        buffer[7] = Tag::Last.into();
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(
+            Tag::BlankScreen,
+            &[if self.power_down { 1 } else { 0 }],
+            RESP_LEN,
+        )
     }
 }
 
-// TODO - sanity checks/result?
-impl From<&[u32; MAILBOX_BUFFER_LEN]> for BlankScreenResp {
-    fn from(buffer: &[u32; MAILBOX_BUFFER_LEN]) -> BlankScreenResp {
-        assert_eq!(buffer[2], Tag::BlankScreen.into());
-        //assert_eq!(buffer[3], RESP_LEN);
-        BlankScreenResp {
-            power_down: if buffer[5] == 0 { false } else { true },
-        }
+impl PropertyTagResp for BlankScreenResp {
+    const TAG: Tag = Tag::BlankScreen;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(BlankScreenResp {
+            power_down: if value[0] == 0 { false } else { true },
+        })
     }
 }
@@ -0,0 +1,40 @@
+use mailbox::Error;
+
+use super::Tag;
+
+/// Clocks managed by the firmware
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ClockId {
+    Emmc,
+    Uart,
+    Arm,
+    Core,
+    V3d,
+    Pwm,
+}
+
+impl From<ClockId> for u32 {
+    fn from(id: ClockId) -> u32 {
+        match id {
+            ClockId::Emmc => 1,
+            ClockId::Uart => 2,
+            ClockId::Arm => 3,
+            ClockId::Core => 4,
+            ClockId::V3d => 5,
+            ClockId::Pwm => 10,
+        }
+    }
+}
+
+/// Parses the clock id of a `tag` response
+pub fn parse_clock_id(id: u32, tag: Tag) -> Result<ClockId, Error> {
+    match id {
+        1 => Ok(ClockId::Emmc),
+        2 => Ok(ClockId::Uart),
+        3 => Ok(ClockId::Arm),
+        4 => Ok(ClockId::Core),
+        5 => Ok(ClockId::V3d),
+        10 => Ok(ClockId::Pwm),
+        _ => Err(Error::InvalidResponse(tag)),
+    }
+}
@@ -1,8 +1,12 @@
 use super::super::cache::{bus_address_bits, cpu_address_bits};
+use edid::Edid;
+use mailbox::{Channel, Error, Mailbox};
+
 use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyResponse;
 use super::Tag;
-use super::MAILBOX_BUFFER_LEN;
-use super::REQUEST;
+use super::{GetEdidBlockCmd, GetEdidBlockResp, GetFbPhySizeCmd, GetFbPhySizeResp};
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum PixelOrder {
@@ -10,6 +14,8 @@ pub enum PixelOrder {
     RGB,
 }
 
+/// Use a `virt_height` of twice the `phy_height` for page flipping, see
+/// `page_flipped()`
 #[derive(Debug, Copy, Clone)]
 pub struct FramebufferCmd {
     pub phy_width: u32,
@@ -18,6 +24,9 @@ pub struct FramebufferCmd {
     pub virt_height: u32,
     pub x_offset: u32,
     pub y_offset: u32,
+    /// Bits per pixel, 8 (palette), 16, 24 or 32
+    pub depth: u32,
+    pub pixel_order: PixelOrder,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -25,6 +34,9 @@ pub struct FramebufferResp {
     // TODO - what else is useful?
     pub phy_width: u32,
     pub phy_height: u32,
+    pub virt_width: u32,
+    pub virt_height: u32,
+    pub depth: u32,
     pub pitch: u32,
     pub pixel_order: PixelOrder,
     pub bus_paddr: u32,
@@ -32,72 +44,119 @@ pub struct FramebufferResp {
 }
 
 impl MailboxMsgBufferConstructor for FramebufferCmd {
-    fn construct_buffer(&self, buffer: &mut [u32; MAILBOX_BUFFER_LEN]) {
-        buffer[0] = 35 * 4;
-        buffer[1] = REQUEST;
-
-        buffer[2] = Tag::SetPhySize.into();
-        buffer[3] = 8;
-        buffer[4] = 8;
-        buffer[5] = self.phy_width;
-        buffer[6] = self.phy_height;
-
-        buffer[7] = Tag::SetVirtSize.into();
-        buffer[8] = 8;
-        buffer[9] = 8;
-        buffer[10] = self.virt_width;
-        buffer[11] = self.virt_height;
-
-        buffer[12] = Tag::SetVirtOffset.into();
-        buffer[13] = 8;
-        buffer[14] = 8;
-        buffer[15] = self.x_offset;
-        buffer[16] = self.y_offset;
-
-        buffer[17] = Tag::SetDepth.into();
-        buffer[18] = 4;
-        buffer[19] = 4;
-        buffer[20] = 32;
-
-        buffer[21] = Tag::SetPixelOrder.into();
-        buffer[22] = 4;
-        buffer[23] = 4;
-        buffer[24] = PixelOrder::RGB.into();
-
-        buffer[25] = Tag::AllocBuffer.into();
-        buffer[26] = 8;
-        buffer[27] = 8;
-        buffer[28] = 4096;
-        buffer[29] = 0;
-
-        buffer[30] = Tag::GetPitch.into();
-        buffer[31] = 4;
-        buffer[32] = 4;
-        buffer[33] = 0;
-
-        buffer[34] = Tag::Last.into();
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::SetPhySize, &[self.phy_width, self.phy_height], 8)?;
+        msg.push_tag(Tag::SetVirtSize, &[self.virt_width, self.virt_height], 8)?;
+        msg.push_tag(Tag::SetVirtOffset, &[self.x_offset, self.y_offset], 8)?;
+        msg.push_tag(Tag::SetDepth, &[self.depth], 4)?;
+        msg.push_tag(Tag::SetPixelOrder, &[self.pixel_order.into()], 4)?;
+        msg.push_tag(Tag::AllocBuffer, &[4096], 8)?;
+        msg.push_tag(Tag::GetPitch, &[], 4)
     }
 }
 
-// TODO - sanity checks/result?
-impl From<&[u32; MAILBOX_BUFFER_LEN]> for FramebufferResp {
-    fn from(buffer: &[u32; MAILBOX_BUFFER_LEN]) -> FramebufferResp {
-        // depth
-        assert_eq!(buffer[20], 32);
-        // buffer
-        assert_ne!(buffer[28], 0);
+impl FramebufferCmd {
+    /// 32 bit RGB framebuffer at the native resolution of the attached
+    /// display, from its EDID preferred timing
+    ///
+    /// Falls back to the size reported by `GetFbPhySizeCmd` if there is
+    /// no usable EDID, e.g. composite output or QEMU
+    pub fn from_preferred_mode(mbox: &mut Mailbox) -> Result<Self, Error> {
+        let (width, height) = match preferred_mode(mbox) {
+            Some(mode) => mode,
+            None => {
+                let size: GetFbPhySizeResp =
+                    mbox.call_batch(Channel::Prop, &GetFbPhySizeCmd)?.get()?;
+                (size.width, size.height)
+            }
+        };
+
+        if (width == 0) || (height == 0) {
+            return Err(Error::InvalidResponse(Tag::GetPhySize));
+        }
 
-        // Make sure bus address bits are correct, for QEMU mostly
-        let bus_paddr = buffer[28] | bus_address_bits::ALIAS_4_L2_COHERENT;
+        Ok(FramebufferCmd {
+            phy_width: width,
+            phy_height: height,
+            virt_width: width,
+            virt_height: height,
+            x_offset: 0,
+            y_offset: 0,
+            depth: 32,
+            pixel_order: PixelOrder::RGB,
+        })
+    }
+
+    /// Doubles the virtual height for page flipping, the two pages are
+    /// stacked vertically and the first one is displayed
+    pub fn page_flipped(self) -> Self {
+        FramebufferCmd {
+            virt_width: self.phy_width,
+            virt_height: 2 * self.phy_height,
+            x_offset: 0,
+            y_offset: 0,
+            ..self
+        }
+    }
+}
+
+fn preferred_mode(mbox: &mut Mailbox) -> Option<(u32, u32)> {
+    let block: GetEdidBlockResp = mbox
+        .call_batch(Channel::Prop, &GetEdidBlockCmd { block: 0 })
+        .and_then(|resp| resp.get())
+        .ok()?;
+
+    let timing = Edid::parse(&block.data).ok()?.preferred?;
 
-        FramebufferResp {
-            phy_width: buffer[5],
-            phy_height: buffer[6],
-            pitch: buffer[33],
-            pixel_order: buffer[24].into(),
+    // Interlaced timings report the lines of a single field
+    let height = if timing.interlaced {
+        2 * timing.v_active
+    } else {
+        timing.v_active
+    };
+
+    Some((timing.h_active, height))
+}
+
+impl FramebufferResp {
+    /// Parses the responses to the tags appended by `FramebufferCmd`
+    pub fn parse(resp: &PropertyResponse) -> Result<Self, Error> {
+        let phy_size = resp.value(Tag::SetPhySize, 8)?;
+        let virt_size = resp.value(Tag::SetVirtSize, 8)?;
+        let depth = resp.value(Tag::SetDepth, 4)?;
+        let pixel_order = resp.value(Tag::SetPixelOrder, 4)?;
+        let buffer = resp.value(Tag::AllocBuffer, 8)?;
+        let pitch = resp.value(Tag::GetPitch, 4)?;
+
+        match depth[0] {
+            8 | 16 | 24 | 32 => (),
+            _ => return Err(Error::InvalidResponse(Tag::SetDepth)),
+        }
+
+        let pixel_order = match pixel_order[0] {
+            0 => PixelOrder::BGR,
+            1 => PixelOrder::RGB,
+            _ => return Err(Error::InvalidResponse(Tag::SetPixelOrder)),
+        };
+
+        if buffer[0] == 0 {
+            return Err(Error::InvalidResponse(Tag::AllocBuffer));
+        }
+
+        // Make sure bus address bits are correct, for QEMU mostly
+        let bus_paddr = buffer[0] | bus_address_bits::ALIAS_4_L2_COHERENT;
+
+        Ok(FramebufferResp {
+            phy_width: phy_size[0],
+            phy_height: phy_size[1],
+            virt_width: virt_size[0],
+            virt_height: virt_size[1],
+            depth: depth[0],
+            pitch: pitch[0],
+            pixel_order,
             bus_paddr,
             paddr: bus_paddr & cpu_address_bits::MASK,
-        }
+        })
     }
 }
 
@@ -0,0 +1,37 @@
+use mailbox::Error;
+
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+
+pub const RESP_LEN: usize = 6;
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetBoardMacCmd;
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetBoardMacResp {
+    /// In network byte order
+    pub mac: [u8; 6],
+}
+
+impl MailboxMsgBufferConstructor for GetBoardMacCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::GetBoardMac, &[], RESP_LEN)
+    }
+}
+
+impl PropertyTagResp for GetBoardMacResp {
+    const TAG: Tag = Tag::GetBoardMac;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        let mut mac = [0; 6];
+        for (i, b) in mac.iter_mut().enumerate() {
+            *b = (value[i / 4] >> ((i % 4) * 8)) as u8;
+        }
+
+        Ok(GetBoardMacResp { mac })
+    }
+}
@@ -0,0 +1,31 @@
+use mailbox::Error;
+
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+
+pub const RESP_LEN: usize = 4;
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetBoardModelCmd;
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetBoardModelResp {
+    pub model: u32,
+}
+
+impl MailboxMsgBufferConstructor for GetBoardModelCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::GetBoardModel, &[], RESP_LEN)
+    }
+}
+
+impl PropertyTagResp for GetBoardModelResp {
+    const TAG: Tag = Tag::GetBoardModel;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(GetBoardModelResp { model: value[0] })
+    }
+}
@@ -0,0 +1,137 @@
+use mailbox::Error;
+
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+
+pub const RESP_LEN: usize = 4;
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetBoardRevisionCmd;
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetBoardRevisionResp {
+    /// Revision code, see `BoardInfo::decode`
+    pub revision: u32,
+}
+
+impl MailboxMsgBufferConstructor for GetBoardRevisionCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::GetBoardRevision, &[], RESP_LEN)
+    }
+}
+
+impl PropertyTagResp for GetBoardRevisionResp {
+    const TAG: Tag = Tag::GetBoardRevision;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(GetBoardRevisionResp { revision: value[0] })
+    }
+}
+
+/// Board model, from the revision code
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BoardModel {
+    A,
+    B,
+    APlus,
+    BPlus,
+    Pi2B,
+    Alpha,
+    CM1,
+    Pi3B,
+    Zero,
+    CM3,
+    ZeroW,
+    Pi3BPlus,
+    Pi3APlus,
+    CM3Plus,
+    Unknown(u32),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Processor {
+    BCM2835,
+    BCM2836,
+    BCM2837,
+    Unknown(u32),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Manufacturer {
+    SonyUK,
+    Egoman,
+    Embest,
+    SonyJapan,
+    Stadium,
+    Unknown(u32),
+}
+
+/// Board information decoded from a new-style revision code
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BoardInfo {
+    pub model: BoardModel,
+    /// Size in bytes
+    pub memory_size: u64,
+    pub processor: Processor,
+    pub manufacturer: Manufacturer,
+}
+
+/// Set in new-style revision codes
+const NEW_STYLE: u32 = 1 << 23;
+
+impl BoardInfo {
+    /// Returns `None` for old-style revision codes, which are only used
+    /// by the first BCM2835 boards
+    pub fn decode(revision: u32) -> Option<BoardInfo> {
+        if (revision & NEW_STYLE) == 0 {
+            return None;
+        }
+
+        let model = match (revision >> 4) & 0xFF {
+            0x0 => BoardModel::A,
+            0x1 => BoardModel::B,
+            0x2 => BoardModel::APlus,
+            0x3 => BoardModel::BPlus,
+            0x4 => BoardModel::Pi2B,
+            0x5 => BoardModel::Alpha,
+            0x6 => BoardModel::CM1,
+            0x8 => BoardModel::Pi3B,
+            0x9 => BoardModel::Zero,
+            0xA => BoardModel::CM3,
+            0xC => BoardModel::ZeroW,
+            0xD => BoardModel::Pi3BPlus,
+            0xE => BoardModel::Pi3APlus,
+            0x10 => BoardModel::CM3Plus,
+            m => BoardModel::Unknown(m),
+        };
+
+        let processor = match (revision >> 12) & 0xF {
+            0 => Processor::BCM2835,
+            1 => Processor::BCM2836,
+            2 => Processor::BCM2837,
+            p => Processor::Unknown(p),
+        };
+
+        let manufacturer = match (revision >> 16) & 0xF {
+            0 => Manufacturer::SonyUK,
+            1 => Manufacturer::Egoman,
+            2 | 4 => Manufacturer::Embest,
+            3 => Manufacturer::SonyJapan,
+            5 => Manufacturer::Stadium,
+            m => Manufacturer::Unknown(m),
+        };
+
+        // 256 MB << n
+        let memory_size: u64 = (256 * 1024 * 1024) << ((revision >> 20) & 0x7);
+
+        Some(BoardInfo {
+            model,
+            memory_size,
+            processor,
+            manufacturer,
+        })
+    }
+}
@@ -0,0 +1,44 @@
+use mailbox::Error;
+
+use super::clock_id::{parse_clock_id, ClockId};
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+
+pub const RESP_LEN: usize = 8;
+
+/// Clock state bits
+pub const CLOCK_STATE_ON: u32 = 1 << 0;
+pub const CLOCK_STATE_NOT_EXISTS: u32 = 1 << 1;
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetClockStateCmd {
+    pub id: ClockId,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetClockStateResp {
+    pub id: ClockId,
+    pub on: bool,
+    pub exists: bool,
+}
+
+impl MailboxMsgBufferConstructor for GetClockStateCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::GetClockState, &[self.id.into()], RESP_LEN)
+    }
+}
+
+impl PropertyTagResp for GetClockStateResp {
+    const TAG: Tag = Tag::GetClockState;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(GetClockStateResp {
+            id: parse_clock_id(value[0], Self::TAG)?,
+            on: (value[1] & CLOCK_STATE_ON) != 0,
+            exists: (value[1] & CLOCK_STATE_NOT_EXISTS) == 0,
+        })
+    }
+}
@@ -0,0 +1,54 @@
+use heapless::consts::U32;
+use heapless::Vec;
+use mailbox::Error;
+
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+
+/// Up to 32 clocks
+pub const RESP_LEN: usize = 32 * 8;
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetClocksCmd;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ClockEntry {
+    /// Zero for a root clock
+    pub parent_id: u32,
+    pub id: u32,
+}
+
+/// Not `Copy`, so it's only available through `PropertyResponse::get()`,
+/// `Mailbox::call` returns `Error::BatchOnly`
+#[derive(Debug, Clone)]
+pub struct GetClocksResp {
+    pub clocks: Vec<ClockEntry, U32>,
+}
+
+impl MailboxMsgBufferConstructor for GetClocksCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::GetClocks, &[], RESP_LEN)
+    }
+}
+
+impl PropertyTagResp for GetClocksResp {
+    const TAG: Tag = Tag::GetClocks;
+    const VALUE_LEN: usize = 0;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        let mut clocks = Vec::new();
+
+        for pair in value.chunks(2).filter(|p| p.len() == 2) {
+            clocks
+                .push(ClockEntry {
+                    parent_id: pair[0],
+                    id: pair[1],
+                })
+                .map_err(|_| Error::TruncatedResponse)?;
+        }
+
+        Ok(GetClocksResp { clocks })
+    }
+}
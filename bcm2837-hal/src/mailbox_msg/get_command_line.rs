@@ -0,0 +1,50 @@
+use heapless::consts::U1024;
+use heapless::{String, Vec};
+use mailbox::Error;
+
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+
+pub const RESP_LEN: usize = 1024;
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetCommandLineCmd;
+
+/// Not `Copy`, so it's only available through `PropertyResponse::get()`,
+/// `Mailbox::call` returns `Error::BatchOnly`
+#[derive(Debug, Clone)]
+pub struct GetCommandLineResp {
+    pub cmdline: String<U1024>,
+}
+
+impl MailboxMsgBufferConstructor for GetCommandLineCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::GetCommandLine, &[], RESP_LEN)
+    }
+}
+
+impl PropertyTagResp for GetCommandLineResp {
+    const TAG: Tag = Tag::GetCommandLine;
+    const VALUE_LEN: usize = 0;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        let mut bytes: Vec<u8, U1024> = Vec::new();
+
+        // Stops at the NUL terminator, if any
+        let chars = value
+            .iter()
+            .flat_map(|w| (0..4).map(move |i| (w >> (i * 8)) as u8))
+            .take_while(|&b| b != 0);
+
+        for b in chars {
+            bytes.push(b).map_err(|_| Error::TruncatedResponse)?;
+        }
+
+        let cmdline =
+            String::from_utf8(bytes).map_err(|_| Error::InvalidResponse(Tag::GetCommandLine))?;
+
+        Ok(GetCommandLineResp { cmdline })
+    }
+}
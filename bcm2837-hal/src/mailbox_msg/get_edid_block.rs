@@ -0,0 +1,62 @@
+use core::fmt;
+use mailbox::Error;
+
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+
+/// Bytes in an EDID block
+pub const EDID_BLOCK_LEN: usize = 128;
+
+/// Block number, status and the block
+pub const RESP_LEN: usize = 8 + EDID_BLOCK_LEN;
+
+/// Reads an EDID block of the attached display, block 0 is the base block
+#[derive(Debug, Copy, Clone)]
+pub struct GetEdidBlockCmd {
+    pub block: u32,
+}
+
+#[derive(Copy, Clone)]
+pub struct GetEdidBlockResp {
+    pub block: u32,
+    pub data: [u8; EDID_BLOCK_LEN],
+}
+
+impl MailboxMsgBufferConstructor for GetEdidBlockCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::GetEdidBlock, &[self.block], RESP_LEN)
+    }
+}
+
+impl PropertyTagResp for GetEdidBlockResp {
+    const TAG: Tag = Tag::GetEdidBlock;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        // Non-zero if there is no display, or no such block
+        if value[1] != 0 {
+            return Err(Error::InvalidResponse(Self::TAG));
+        }
+
+        let mut data = [0; EDID_BLOCK_LEN];
+        for (i, b) in data.iter_mut().enumerate() {
+            *b = (value[2 + (i / 4)] >> ((i % 4) * 8)) as u8;
+        }
+
+        Ok(GetEdidBlockResp {
+            block: value[0],
+            data,
+        })
+    }
+}
+
+impl fmt::Debug for GetEdidBlockResp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GetEdidBlockResp")
+            .field("block", &self.block)
+            .field("data", &&self.data[..])
+            .finish()
+    }
+}
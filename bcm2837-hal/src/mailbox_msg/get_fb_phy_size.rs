@@ -1,10 +1,11 @@
+use mailbox::Error;
+
 use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
 use super::Tag;
-use super::MAILBOX_BUFFER_LEN;
-use super::REQUEST;
 
-pub const CMD_LEN: u32 = 8;
-pub const RESP_LEN: u32 = 8;
+pub const RESP_LEN: usize = 8;
 
 #[derive(Debug, Copy, Clone)]
 pub struct GetFbPhySizeCmd;
@@ -16,24 +17,19 @@ pub struct GetFbPhySizeResp {
 }
 
 impl MailboxMsgBufferConstructor for GetFbPhySizeCmd {
-    fn construct_buffer(&self, buffer: &mut [u32; MAILBOX_BUFFER_LEN]) {
-        buffer[0] = 8 * 4;
-        buffer[1] = REQUEST;
-        buffer[2] = Tag::GetPhySize.into();
-        buffer[3] = CMD_LEN;
-        buffer[4] = RESP_LEN;
-        buffer[5] = 0;
-        buffer[6] = 0;
-        buffer[7] = Tag::Last.into();
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::GetPhySize, &[], RESP_LEN)
     }
 }
 
-impl From<&[u32; MAILBOX_BUFFER_LEN]> for GetFbPhySizeResp {
-    fn from(buffer: &[u32; MAILBOX_BUFFER_LEN]) -> GetFbPhySizeResp {
-        assert_eq!(buffer[2], Tag::GetPhySize.into());
-        GetFbPhySizeResp {
-            width: buffer[5],
-            height: buffer[6],
-        }
+impl PropertyTagResp for GetFbPhySizeResp {
+    const TAG: Tag = Tag::GetPhySize;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(GetFbPhySizeResp {
+            width: value[0],
+            height: value[1],
+        })
     }
 }
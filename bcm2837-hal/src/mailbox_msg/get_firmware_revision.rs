@@ -0,0 +1,31 @@
+use mailbox::Error;
+
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+
+pub const RESP_LEN: usize = 4;
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetFirmwareRevisionCmd;
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetFirmwareRevisionResp {
+    pub revision: u32,
+}
+
+impl MailboxMsgBufferConstructor for GetFirmwareRevisionCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::GetFirmwareRevision, &[], RESP_LEN)
+    }
+}
+
+impl PropertyTagResp for GetFirmwareRevisionResp {
+    const TAG: Tag = Tag::GetFirmwareRevision;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(GetFirmwareRevisionResp { revision: value[0] })
+    }
+}
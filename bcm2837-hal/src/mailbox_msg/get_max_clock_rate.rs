@@ -0,0 +1,39 @@
+use mailbox::Error;
+use time::Hertz;
+
+use super::clock_id::{parse_clock_id, ClockId};
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+
+pub const RESP_LEN: usize = 8;
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetMaxClockRateCmd {
+    pub id: ClockId,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetMaxClockRateResp {
+    pub id: ClockId,
+    pub rate: Hertz,
+}
+
+impl MailboxMsgBufferConstructor for GetMaxClockRateCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::GetMaxClockRate, &[self.id.into()], RESP_LEN)
+    }
+}
+
+impl PropertyTagResp for GetMaxClockRateResp {
+    const TAG: Tag = Tag::GetMaxClockRate;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(GetMaxClockRateResp {
+            id: parse_clock_id(value[0], Self::TAG)?,
+            rate: Hertz(value[1]),
+        })
+    }
+}
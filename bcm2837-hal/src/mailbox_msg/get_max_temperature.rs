@@ -0,0 +1,38 @@
+use mailbox::Error;
+
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+
+pub const RESP_LEN: usize = 8;
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetMaxTemperatureCmd {
+    pub id: u32,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetMaxTemperatureResp {
+    pub id: u32,
+    /// Thousandths of a degree C, above which the clocks are throttled
+    pub value: u32,
+}
+
+impl MailboxMsgBufferConstructor for GetMaxTemperatureCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::GetMaxTemperature, &[self.id], RESP_LEN)
+    }
+}
+
+impl PropertyTagResp for GetMaxTemperatureResp {
+    const TAG: Tag = Tag::GetMaxTemperature;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(GetMaxTemperatureResp {
+            id: value[0],
+            value: value[1],
+        })
+    }
+}
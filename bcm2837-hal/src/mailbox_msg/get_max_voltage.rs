@@ -0,0 +1,39 @@
+use mailbox::Error;
+
+use super::voltage_id::{parse_voltage, parse_voltage_id, VoltageId};
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+
+pub const RESP_LEN: usize = 8;
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetMaxVoltageCmd {
+    pub id: VoltageId,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetMaxVoltageResp {
+    pub id: VoltageId,
+    /// Offset from 1.2 V in 0.025 V steps
+    pub value: i32,
+}
+
+impl MailboxMsgBufferConstructor for GetMaxVoltageCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::GetMaxVoltage, &[self.id.into()], RESP_LEN)
+    }
+}
+
+impl PropertyTagResp for GetMaxVoltageResp {
+    const TAG: Tag = Tag::GetMaxVoltage;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(GetMaxVoltageResp {
+            id: parse_voltage_id(value[0], Self::TAG)?,
+            value: parse_voltage(value[1], Self::TAG)?,
+        })
+    }
+}
@@ -0,0 +1,46 @@
+use mailbox::Error;
+
+use super::power_device::{parse_power_device, PowerDevice};
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+
+pub const RESP_LEN: usize = 8;
+
+/// Power state bits
+pub const POWER_STATE_ON: u32 = 1 << 0;
+pub const POWER_STATE_NOT_EXISTS: u32 = 1 << 1;
+/// Only used in requests, wait for the power to become stable
+pub const POWER_STATE_WAIT: u32 = 1 << 1;
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetPowerStateCmd {
+    pub device: PowerDevice,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetPowerStateResp {
+    pub device: PowerDevice,
+    pub on: bool,
+    pub exists: bool,
+}
+
+impl MailboxMsgBufferConstructor for GetPowerStateCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::GetPowerState, &[self.device.into()], RESP_LEN)
+    }
+}
+
+impl PropertyTagResp for GetPowerStateResp {
+    const TAG: Tag = Tag::GetPowerState;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(GetPowerStateResp {
+            device: parse_power_device(value[0], Self::TAG)?,
+            on: (value[1] & POWER_STATE_ON) != 0,
+            exists: (value[1] & POWER_STATE_NOT_EXISTS) == 0,
+        })
+    }
+}
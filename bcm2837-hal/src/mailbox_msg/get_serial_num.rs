@@ -1,10 +1,11 @@
+use mailbox::Error;
+
 use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
 use super::Tag;
-use super::MAILBOX_BUFFER_LEN;
-use super::REQUEST;
 
-pub const CMD_LEN: u32 = 8;
-pub const RESP_LEN: u32 = 8;
+pub const RESP_LEN: usize = 8;
 
 #[derive(Debug, Copy, Clone)]
 pub struct GetSerialNumCmd;
@@ -15,25 +16,18 @@ pub struct GetSerialNumResp {
 }
 
 impl MailboxMsgBufferConstructor for GetSerialNumCmd {
-    fn construct_buffer(&self, buffer: &mut [u32; MAILBOX_BUFFER_LEN]) {
-        buffer[0] = 8 * 4;
-        buffer[1] = REQUEST;
-        buffer[2] = Tag::GetSerialNum.into();
-        buffer[3] = CMD_LEN;
-        buffer[4] = RESP_LEN;
-        buffer[5] = 0;
-        buffer[6] = 0;
-        buffer[7] = Tag::Last.into();
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::GetSerialNum, &[], RESP_LEN)
     }
 }
 
-// TODO - sanity checks/result?
-impl From<&[u32; MAILBOX_BUFFER_LEN]> for GetSerialNumResp {
-    fn from(buffer: &[u32; MAILBOX_BUFFER_LEN]) -> GetSerialNumResp {
-        assert_eq!(buffer[2], Tag::GetSerialNum.into());
-        //assert_eq!(buffer[3], RESP_LEN);
-        GetSerialNumResp {
-            serial_number: buffer[5] as u64 | (buffer[6] as u64) << 32,
-        }
+impl PropertyTagResp for GetSerialNumResp {
+    const TAG: Tag = Tag::GetSerialNum;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(GetSerialNumResp {
+            serial_number: value[0] as u64 | (value[1] as u64) << 32,
+        })
     }
 }
@@ -1,10 +1,11 @@
+use mailbox::Error;
+
 use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
 use super::Tag;
-use super::MAILBOX_BUFFER_LEN;
-use super::REQUEST;
 
-pub const CMD_LEN: u32 = 8;
-pub const RESP_LEN: u32 = 8;
+pub const RESP_LEN: usize = 8;
 
 #[derive(Debug, Copy, Clone)]
 pub struct GetTemperatureCmd {
@@ -18,26 +19,19 @@ pub struct GetTemperatureResp {
 }
 
 impl MailboxMsgBufferConstructor for GetTemperatureCmd {
-    fn construct_buffer(&self, buffer: &mut [u32; MAILBOX_BUFFER_LEN]) {
-        buffer[0] = 8 * 4;
-        buffer[1] = REQUEST;
-        buffer[2] = Tag::GetTemperature.into();
-        buffer[3] = CMD_LEN;
-        buffer[4] = RESP_LEN;
-        buffer[5] = self.id;
-        buffer[6] = 0;
-        buffer[7] = Tag::Last.into();
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::GetTemperature, &[self.id], RESP_LEN)
     }
 }
 
-// TODO - sanity checks/result?
-impl From<&[u32; MAILBOX_BUFFER_LEN]> for GetTemperatureResp {
-    fn from(buffer: &[u32; MAILBOX_BUFFER_LEN]) -> GetTemperatureResp {
-        assert_eq!(buffer[2], Tag::GetTemperature.into());
-        //assert_eq!(buffer[3], RESP_LEN);
-        GetTemperatureResp {
-            id: buffer[5],
-            value: buffer[6],
-        }
+impl PropertyTagResp for GetTemperatureResp {
+    const TAG: Tag = Tag::GetTemperature;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(GetTemperatureResp {
+            id: value[0],
+            value: value[1],
+        })
     }
 }
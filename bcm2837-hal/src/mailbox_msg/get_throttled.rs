@@ -0,0 +1,68 @@
+use mailbox::Error;
+
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+
+pub const RESP_LEN: usize = 4;
+
+/// Throttling bits, the sticky bits are shifted up by `THROTTLED_STICKY_SHIFT`
+pub const THROTTLED_UNDER_VOLTAGE: u32 = 1 << 0;
+pub const THROTTLED_FREQUENCY_CAPPED: u32 = 1 << 1;
+pub const THROTTLED_THROTTLED: u32 = 1 << 2;
+pub const THROTTLED_SOFT_TEMP_LIMIT: u32 = 1 << 3;
+pub const THROTTLED_STICKY_SHIFT: u32 = 16;
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetThrottledCmd;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ThrottledFlags {
+    pub under_voltage: bool,
+    pub frequency_capped: bool,
+    pub throttled: bool,
+    pub soft_temp_limit: bool,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetThrottledResp {
+    /// Conditions active now
+    pub current: ThrottledFlags,
+    /// Conditions that have occurred since boot
+    pub sticky: ThrottledFlags,
+}
+
+impl ThrottledFlags {
+    fn from_bits(bits: u32) -> Self {
+        ThrottledFlags {
+            under_voltage: (bits & THROTTLED_UNDER_VOLTAGE) != 0,
+            frequency_capped: (bits & THROTTLED_FREQUENCY_CAPPED) != 0,
+            throttled: (bits & THROTTLED_THROTTLED) != 0,
+            soft_temp_limit: (bits & THROTTLED_SOFT_TEMP_LIMIT) != 0,
+        }
+    }
+
+    /// Returns true if any of the conditions are set
+    pub fn any(&self) -> bool {
+        self.under_voltage || self.frequency_capped || self.throttled || self.soft_temp_limit
+    }
+}
+
+impl MailboxMsgBufferConstructor for GetThrottledCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::GetThrottled, &[], RESP_LEN)
+    }
+}
+
+impl PropertyTagResp for GetThrottledResp {
+    const TAG: Tag = Tag::GetThrottled;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(GetThrottledResp {
+            current: ThrottledFlags::from_bits(value[0]),
+            sticky: ThrottledFlags::from_bits(value[0] >> THROTTLED_STICKY_SHIFT),
+        })
+    }
+}
@@ -0,0 +1,41 @@
+use mailbox::Error;
+use time::MicroSeconds;
+
+use super::power_device::{parse_power_device, PowerDevice};
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+
+pub const RESP_LEN: usize = 8;
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetTimingCmd {
+    pub device: PowerDevice,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetTimingResp {
+    pub device: PowerDevice,
+    /// Time to wait for the power to become stable after turning on,
+    /// zero if the device doesn't exist
+    pub wait: MicroSeconds,
+}
+
+impl MailboxMsgBufferConstructor for GetTimingCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::GetTiming, &[self.device.into()], RESP_LEN)
+    }
+}
+
+impl PropertyTagResp for GetTimingResp {
+    const TAG: Tag = Tag::GetTiming;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(GetTimingResp {
+            device: parse_power_device(value[0], Self::TAG)?,
+            wait: MicroSeconds(value[1]),
+        })
+    }
+}
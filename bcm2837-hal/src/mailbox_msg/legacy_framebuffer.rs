@@ -0,0 +1,78 @@
+//! Framebuffer channel message, predates the property interface
+
+use super::super::cache::{bus_address_bits, cpu_address_bits};
+use mailbox::Error;
+
+/// Words in the framebuffer channel message
+pub const LEGACY_FRAMEBUFFER_LEN: usize = 10;
+
+#[derive(Debug, Copy, Clone)]
+pub struct LegacyFramebufferCmd {
+    pub phy_width: u32,
+    pub phy_height: u32,
+    pub virt_width: u32,
+    pub virt_height: u32,
+    /// Bits per pixel
+    pub depth: u32,
+    pub x_offset: u32,
+    pub y_offset: u32,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct LegacyFramebufferResp {
+    pub phy_width: u32,
+    pub phy_height: u32,
+    pub pitch: u32,
+    pub bus_paddr: u32,
+    pub paddr: u32,
+    /// Size in bytes
+    pub size: u32,
+}
+
+impl LegacyFramebufferCmd {
+    pub fn construct_buffer(&self, buffer: &mut [u32]) -> Result<(), Error> {
+        if buffer.len() < LEGACY_FRAMEBUFFER_LEN {
+            return Err(Error::BufferTooSmall);
+        }
+
+        buffer[0] = self.phy_width;
+        buffer[1] = self.phy_height;
+        buffer[2] = self.virt_width;
+        buffer[3] = self.virt_height;
+        // Pitch, filled in by the firmware
+        buffer[4] = 0;
+        buffer[5] = self.depth;
+        buffer[6] = self.x_offset;
+        buffer[7] = self.y_offset;
+        // Pointer and size, filled in by the firmware
+        buffer[8] = 0;
+        buffer[9] = 0;
+
+        Ok(())
+    }
+}
+
+impl LegacyFramebufferResp {
+    pub fn parse(buffer: &[u32]) -> Result<Self, Error> {
+        if buffer.len() < LEGACY_FRAMEBUFFER_LEN {
+            return Err(Error::TruncatedResponse);
+        }
+
+        // No framebuffer was allocated
+        if (buffer[8] == 0) || (buffer[9] == 0) {
+            return Err(Error::BadRequest);
+        }
+
+        // Make sure bus address bits are correct, for QEMU mostly
+        let bus_paddr = buffer[8] | bus_address_bits::ALIAS_4_L2_COHERENT;
+
+        Ok(LegacyFramebufferResp {
+            phy_width: buffer[0],
+            phy_height: buffer[1],
+            pitch: buffer[4],
+            bus_paddr,
+            paddr: bus_paddr & cpu_address_bits::MASK,
+            size: buffer[9],
+        })
+    }
+}
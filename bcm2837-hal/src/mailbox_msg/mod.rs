@@ -1,26 +1,111 @@
 mod blank_screen;
+mod clock_id;
 mod framebuffer;
 mod get_arm_mem;
+mod get_board_mac;
+mod get_board_model;
+mod get_board_revision;
+mod get_clock_rate;
+mod get_clock_state;
+mod get_clocks;
+mod get_command_line;
+mod get_edid_block;
 mod get_fb_phy_size;
+mod get_firmware_revision;
+mod get_max_clock_rate;
+mod get_max_temperature;
+mod get_max_voltage;
+mod get_min_clock_rate;
+mod get_min_voltage;
+mod get_power_state;
 mod get_serial_num;
 mod get_temperature;
+mod get_throttled;
+mod get_timing;
 mod get_vc_mem;
+mod get_voltage;
+mod legacy_framebuffer;
+mod power_device;
+mod property_message;
+mod property_response;
+mod set_clock_rate;
+mod set_clock_state;
+mod set_cursor_info;
 mod set_cursor_state;
+mod set_palette;
+mod set_power_state;
+mod set_virt_offset;
+mod set_voltage;
+mod voltage_id;
+mod wait_for_vsync;
 
 pub use self::blank_screen::{BlankScreenCmd, BlankScreenResp};
+pub use self::clock_id::ClockId;
 pub use self::framebuffer::{FramebufferCmd, FramebufferResp, PixelOrder};
 pub use self::get_arm_mem::{GetArmMemCmd, GetArmMemResp};
+pub use self::get_board_mac::{GetBoardMacCmd, GetBoardMacResp};
+pub use self::get_board_model::{GetBoardModelCmd, GetBoardModelResp};
+pub use self::get_board_revision::{
+    BoardInfo, BoardModel, GetBoardRevisionCmd, GetBoardRevisionResp, Manufacturer, Processor,
+};
+pub use self::get_clock_rate::{GetClockRateCmd, GetClockRateResp};
+pub use self::get_clock_state::{
+    GetClockStateCmd, GetClockStateResp, CLOCK_STATE_NOT_EXISTS, CLOCK_STATE_ON,
+};
+pub use self::get_clocks::{ClockEntry, GetClocksCmd, GetClocksResp};
+pub use self::get_command_line::{GetCommandLineCmd, GetCommandLineResp};
+pub use self::get_edid_block::{GetEdidBlockCmd, GetEdidBlockResp, EDID_BLOCK_LEN};
 pub use self::get_fb_phy_size::{GetFbPhySizeCmd, GetFbPhySizeResp};
+pub use self::get_firmware_revision::{GetFirmwareRevisionCmd, GetFirmwareRevisionResp};
+pub use self::get_max_clock_rate::{GetMaxClockRateCmd, GetMaxClockRateResp};
+pub use self::get_max_temperature::{GetMaxTemperatureCmd, GetMaxTemperatureResp};
+pub use self::get_max_voltage::{GetMaxVoltageCmd, GetMaxVoltageResp};
+pub use self::get_min_clock_rate::{GetMinClockRateCmd, GetMinClockRateResp};
+pub use self::get_min_voltage::{GetMinVoltageCmd, GetMinVoltageResp};
+pub use self::get_power_state::{
+    GetPowerStateCmd, GetPowerStateResp, POWER_STATE_NOT_EXISTS, POWER_STATE_ON, POWER_STATE_WAIT,
+};
 pub use self::get_serial_num::{GetSerialNumCmd, GetSerialNumResp};
 pub use self::get_temperature::{GetTemperatureCmd, GetTemperatureResp};
+pub use self::get_throttled::{GetThrottledCmd, GetThrottledResp, ThrottledFlags};
+pub use self::get_timing::{GetTimingCmd, GetTimingResp};
 pub use self::get_vc_mem::{GetVcMemCmd, GetVcMemResp};
+pub use self::get_voltage::{GetVoltageCmd, GetVoltageResp};
+pub use self::legacy_framebuffer::{
+    LegacyFramebufferCmd, LegacyFramebufferResp, LEGACY_FRAMEBUFFER_LEN,
+};
+pub use self::power_device::PowerDevice;
+pub use self::property_message::PropertyMessage;
+pub use self::property_response::{PropertyResponse, RespTag, RespTags, TAG_RESPONSE};
+pub use self::set_clock_rate::{SetClockRateCmd, SetClockRateResp};
+pub use self::set_clock_state::{SetClockStateCmd, SetClockStateResp};
+pub use self::set_cursor_info::{SetCursorInfoCmd, SetCursorInfoResp};
 pub use self::set_cursor_state::{CursorCoord, SetCursorStateCmd, SetCursorStateResp};
+pub use self::set_palette::{SetPaletteCmd, SetPaletteResp, PALETTE_LEN};
+pub use self::set_power_state::{SetPowerStateCmd, SetPowerStateResp};
+pub use self::set_virt_offset::{SetVirtOffsetCmd, SetVirtOffsetResp};
+pub use self::set_voltage::{SetVoltageCmd, SetVoltageResp};
+pub use self::voltage_id::VoltageId;
+pub use self::wait_for_vsync::WaitForVsyncCmd;
+
+use mailbox::Error;
 
 pub const REQUEST: u32 = 0;
-pub const MAILBOX_BUFFER_LEN: usize = 36;
 
+/// Appends the tag(s) of a command to a property message
 pub trait MailboxMsgBufferConstructor {
-    fn construct_buffer(&self, buffer: &mut [u32; MAILBOX_BUFFER_LEN]);
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error>;
+}
+
+/// Parses the value of a single response tag
+pub trait PropertyTagResp: Sized {
+    const TAG: Tag;
+
+    /// Smallest response value length, in bytes, that can be parsed
+    const VALUE_LEN: usize;
+
+    /// `value` is at least `VALUE_LEN` bytes long
+    fn parse(value: &[u32]) -> Result<Self, Error>;
 }
 
 // TODO - compare with linux rpi firmware source for naming
@@ -41,6 +126,33 @@ pub enum Tag {
     SetDepth,
     SetPixelOrder,
     SetVirtOffset,
+    GetClockState,
+    GetClockRate,
+    GetMaxClockRate,
+    GetMinClockRate,
+    SetClockState,
+    SetClockRate,
+    GetFirmwareRevision,
+    GetBoardModel,
+    GetBoardRevision,
+    GetBoardMac,
+    GetClocks,
+    GetCommandLine,
+    GetPowerState,
+    GetTiming,
+    SetPowerState,
+    GetVoltage,
+    GetMaxVoltage,
+    GetMinVoltage,
+    SetVoltage,
+    GetMaxTemperature,
+    GetThrottled,
+    WaitForVsync,
+    SetPalette,
+    SetCursorInfo,
+    GetEdidBlock,
+    /// Tag not known to this crate
+    Unknown(u32),
     #[doc(hidden)]
     _Extensible,
 }
@@ -63,13 +175,88 @@ impl From<Tag> for u32 {
             Tag::SetDepth => 0x48005,
             Tag::SetPixelOrder => 0x48006,
             Tag::SetVirtOffset => 0x48009,
+            Tag::GetClockState => 0x30001,
+            Tag::GetClockRate => 0x30002,
+            Tag::GetMaxClockRate => 0x30004,
+            Tag::GetMinClockRate => 0x30007,
+            Tag::SetClockState => 0x38001,
+            Tag::SetClockRate => 0x38002,
+            Tag::GetFirmwareRevision => 0x1,
+            Tag::GetBoardModel => 0x10001,
+            Tag::GetBoardRevision => 0x10002,
+            Tag::GetBoardMac => 0x10003,
+            Tag::GetClocks => 0x10007,
+            Tag::GetCommandLine => 0x50001,
+            Tag::GetPowerState => 0x20001,
+            Tag::GetTiming => 0x20002,
+            Tag::SetPowerState => 0x28001,
+            Tag::GetVoltage => 0x30003,
+            Tag::GetMaxVoltage => 0x30005,
+            Tag::GetMinVoltage => 0x30008,
+            Tag::SetVoltage => 0x38003,
+            Tag::GetMaxTemperature => 0x3000A,
+            Tag::GetThrottled => 0x30046,
+            Tag::WaitForVsync => 0x4000E,
+            Tag::SetPalette => 0x4800B,
+            Tag::SetCursorInfo => 0x8010,
+            Tag::GetEdidBlock => 0x30020,
+            Tag::Unknown(id) => id,
             _ => unimplemented!(),
         }
     }
 }
 
+impl From<u32> for Tag {
+    fn from(id: u32) -> Tag {
+        match id {
+            0 => Tag::Last,
+            0x8011 => Tag::SetCursorState,
+            0x10004 => Tag::GetSerialNum,
+            0x10005 => Tag::GetArmMem,
+            0x10006 => Tag::GetVcMem,
+            0x30006 => Tag::GetTemperature,
+            0x40001 => Tag::AllocBuffer,
+            0x40002 => Tag::BlankScreen,
+            0x40003 => Tag::GetPhySize,
+            0x40008 => Tag::GetPitch,
+            0x48003 => Tag::SetPhySize,
+            0x48004 => Tag::SetVirtSize,
+            0x48005 => Tag::SetDepth,
+            0x48006 => Tag::SetPixelOrder,
+            0x48009 => Tag::SetVirtOffset,
+            0x30001 => Tag::GetClockState,
+            0x30002 => Tag::GetClockRate,
+            0x30004 => Tag::GetMaxClockRate,
+            0x30007 => Tag::GetMinClockRate,
+            0x38001 => Tag::SetClockState,
+            0x38002 => Tag::SetClockRate,
+            0x1 => Tag::GetFirmwareRevision,
+            0x10001 => Tag::GetBoardModel,
+            0x10002 => Tag::GetBoardRevision,
+            0x10003 => Tag::GetBoardMac,
+            0x10007 => Tag::GetClocks,
+            0x50001 => Tag::GetCommandLine,
+            0x20001 => Tag::GetPowerState,
+            0x20002 => Tag::GetTiming,
+            0x28001 => Tag::SetPowerState,
+            0x30003 => Tag::GetVoltage,
+            0x30005 => Tag::GetMaxVoltage,
+            0x30008 => Tag::GetMinVoltage,
+            0x38003 => Tag::SetVoltage,
+            0x3000A => Tag::GetMaxTemperature,
+            0x30046 => Tag::GetThrottled,
+            0x4000E => Tag::WaitForVsync,
+            0x4800B => Tag::SetPalette,
+            0x8010 => Tag::SetCursorInfo,
+            0x30020 => Tag::GetEdidBlock,
+            _ => Tag::Unknown(id),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub enum Resp {
+    /// Every tag was acknowledged, the first one has no typed response
     Ack,
     GetSerialNumResp(GetSerialNumResp),
     GetTemperatureResp(GetTemperatureResp),
@@ -79,31 +266,77 @@ pub enum Resp {
     BlankScreenResp(BlankScreenResp),
     GetFbPhySizeResp(GetFbPhySizeResp),
     SetCursorStateResp(SetCursorStateResp),
+    GetClockStateResp(GetClockStateResp),
+    SetClockStateResp(SetClockStateResp),
+    GetClockRateResp(GetClockRateResp),
+    GetMaxClockRateResp(GetMaxClockRateResp),
+    GetMinClockRateResp(GetMinClockRateResp),
+    SetClockRateResp(SetClockRateResp),
+    GetFirmwareRevisionResp(GetFirmwareRevisionResp),
+    GetBoardModelResp(GetBoardModelResp),
+    GetBoardRevisionResp(GetBoardRevisionResp),
+    GetBoardMacResp(GetBoardMacResp),
+    GetPowerStateResp(GetPowerStateResp),
+    GetTimingResp(GetTimingResp),
+    SetPowerStateResp(SetPowerStateResp),
+    GetVoltageResp(GetVoltageResp),
+    GetMaxVoltageResp(GetMaxVoltageResp),
+    GetMinVoltageResp(GetMinVoltageResp),
+    SetVoltageResp(SetVoltageResp),
+    GetMaxTemperatureResp(GetMaxTemperatureResp),
+    GetThrottledResp(GetThrottledResp),
+    SetVirtOffsetResp(SetVirtOffsetResp),
+    SetPaletteResp(SetPaletteResp),
+    SetCursorInfoResp(SetCursorInfoResp),
+    GetEdidBlockResp(GetEdidBlockResp),
 }
 
-// TODO - sanity checks/result?
-impl From<&[u32; MAILBOX_BUFFER_LEN]> for Resp {
-    fn from(buffer: &[u32; MAILBOX_BUFFER_LEN]) -> Resp {
-        // TODO - would prefer to match
-        if buffer[2] == Tag::GetSerialNum.into() {
-            Resp::GetSerialNumResp(GetSerialNumResp::from(buffer))
-        } else if buffer[2] == Tag::GetTemperature.into() {
-            Resp::GetTemperatureResp(GetTemperatureResp::from(buffer))
-        } else if buffer[2] == Tag::GetArmMem.into() {
-            Resp::GetArmMemResp(GetArmMemResp::from(buffer))
-        } else if buffer[2] == Tag::GetVcMem.into() {
-            Resp::GetVcMemResp(GetVcMemResp::from(buffer))
-        } else if buffer[2] == Tag::BlankScreen.into() {
-            Resp::BlankScreenResp(BlankScreenResp::from(buffer))
-        } else if buffer[2] == Tag::GetPhySize.into() {
-            Resp::GetFbPhySizeResp(GetFbPhySizeResp::from(buffer))
-        } else if buffer[2] == Tag::SetCursorState.into() {
-            Resp::SetCursorStateResp(SetCursorStateResp::from(buffer))
-        } else if buffer[2] == Tag::SetPhySize.into() {
-            // TODO - gating on the first tag, need to improve this
-            Resp::FramebufferResp(FramebufferResp::from(buffer))
-        } else {
-            Resp::Ack
-        }
+impl Resp {
+    /// Checks every tag of the response, then parses the response to
+    /// the first tag
+    pub fn parse(resp: &PropertyResponse) -> Result<Resp, Error> {
+        resp.check()?;
+
+        let first = match resp.tags().next() {
+            Some(t) => t?.tag,
+            None => return Ok(Resp::Ack),
+        };
+
+        Ok(match first {
+            Tag::GetSerialNum => Resp::GetSerialNumResp(resp.get()?),
+            Tag::GetTemperature => Resp::GetTemperatureResp(resp.get()?),
+            Tag::GetArmMem => Resp::GetArmMemResp(resp.get()?),
+            Tag::GetVcMem => Resp::GetVcMemResp(resp.get()?),
+            Tag::BlankScreen => Resp::BlankScreenResp(resp.get()?),
+            Tag::GetPhySize => Resp::GetFbPhySizeResp(resp.get()?),
+            Tag::SetCursorState => Resp::SetCursorStateResp(resp.get()?),
+            Tag::SetPhySize => Resp::FramebufferResp(FramebufferResp::parse(resp)?),
+            Tag::GetClockState => Resp::GetClockStateResp(resp.get()?),
+            Tag::SetClockState => Resp::SetClockStateResp(resp.get()?),
+            Tag::GetClockRate => Resp::GetClockRateResp(resp.get()?),
+            Tag::GetMaxClockRate => Resp::GetMaxClockRateResp(resp.get()?),
+            Tag::GetMinClockRate => Resp::GetMinClockRateResp(resp.get()?),
+            Tag::SetClockRate => Resp::SetClockRateResp(resp.get()?),
+            Tag::GetFirmwareRevision => Resp::GetFirmwareRevisionResp(resp.get()?),
+            Tag::GetBoardModel => Resp::GetBoardModelResp(resp.get()?),
+            Tag::GetBoardRevision => Resp::GetBoardRevisionResp(resp.get()?),
+            Tag::GetBoardMac => Resp::GetBoardMacResp(resp.get()?),
+            Tag::GetPowerState => Resp::GetPowerStateResp(resp.get()?),
+            Tag::GetTiming => Resp::GetTimingResp(resp.get()?),
+            Tag::SetPowerState => Resp::SetPowerStateResp(resp.get()?),
+            Tag::GetVoltage => Resp::GetVoltageResp(resp.get()?),
+            Tag::GetMaxVoltage => Resp::GetMaxVoltageResp(resp.get()?),
+            Tag::GetMinVoltage => Resp::GetMinVoltageResp(resp.get()?),
+            Tag::SetVoltage => Resp::SetVoltageResp(resp.get()?),
+            Tag::GetMaxTemperature => Resp::GetMaxTemperatureResp(resp.get()?),
+            Tag::GetThrottled => Resp::GetThrottledResp(resp.get()?),
+            Tag::SetVirtOffset => Resp::SetVirtOffsetResp(resp.get()?),
+            Tag::SetPalette => Resp::SetPaletteResp(resp.get()?),
+            Tag::SetCursorInfo => Resp::SetCursorInfoResp(resp.get()?),
+            Tag::GetEdidBlock => Resp::GetEdidBlockResp(resp.get()?),
+            // Too big to be `Copy`, these are only parsed by `PropertyResponse::get()`
+            Tag::GetClocks | Tag::GetCommandLine => return Err(Error::BatchOnly(first)),
+            _ => Resp::Ack,
+        })
     }
 }
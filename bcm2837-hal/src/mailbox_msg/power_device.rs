@@ -0,0 +1,49 @@
+use mailbox::Error;
+
+use super::Tag;
+
+/// Power domains managed by the firmware
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PowerDevice {
+    SdCard,
+    Uart0,
+    Uart1,
+    UsbHcd,
+    I2c0,
+    I2c1,
+    I2c2,
+    Spi,
+    Ccp2tx,
+}
+
+impl From<PowerDevice> for u32 {
+    fn from(device: PowerDevice) -> u32 {
+        match device {
+            PowerDevice::SdCard => 0,
+            PowerDevice::Uart0 => 1,
+            PowerDevice::Uart1 => 2,
+            PowerDevice::UsbHcd => 3,
+            PowerDevice::I2c0 => 4,
+            PowerDevice::I2c1 => 5,
+            PowerDevice::I2c2 => 6,
+            PowerDevice::Spi => 7,
+            PowerDevice::Ccp2tx => 8,
+        }
+    }
+}
+
+/// Parses the device id of a `tag` response
+pub fn parse_power_device(id: u32, tag: Tag) -> Result<PowerDevice, Error> {
+    match id {
+        0 => Ok(PowerDevice::SdCard),
+        1 => Ok(PowerDevice::Uart0),
+        2 => Ok(PowerDevice::Uart1),
+        3 => Ok(PowerDevice::UsbHcd),
+        4 => Ok(PowerDevice::I2c0),
+        5 => Ok(PowerDevice::I2c1),
+        6 => Ok(PowerDevice::I2c2),
+        7 => Ok(PowerDevice::Spi),
+        8 => Ok(PowerDevice::Ccp2tx),
+        _ => Err(Error::InvalidResponse(tag)),
+    }
+}
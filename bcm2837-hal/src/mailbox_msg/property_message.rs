@@ -0,0 +1,111 @@
+//! Property channel message builder
+
+use core::cmp;
+use mailbox::Error;
+
+use super::MailboxMsgBufferConstructor;
+use super::Tag;
+use super::REQUEST;
+
+/// Words in the message header, buffer size and request/response code
+const HEADER_LEN: usize = 2;
+
+/// Words in a tag header, tag, value buffer size and request/response code
+const TAG_HEADER_LEN: usize = 3;
+
+/// Messages are padded to a multiple of 16 bytes
+const ALIGN_LEN: usize = 4;
+
+/// Builds a property channel message in place, any number of tags
+/// followed by the end tag
+pub struct PropertyMessage<'a> {
+    buffer: &'a mut [u32],
+    /// Words used, not including the end tag
+    len: usize,
+}
+
+impl<'a> PropertyMessage<'a> {
+    pub fn new(buffer: &'a mut [u32]) -> Result<Self, Error> {
+        if buffer.len() < padded_len(HEADER_LEN + 1) {
+            return Err(Error::BufferTooSmall);
+        }
+
+        Ok(PropertyMessage {
+            buffer,
+            len: HEADER_LEN,
+        })
+    }
+
+    /// Appends a tag, the value buffer is sized to hold the larger of the
+    /// request and response values and zero filled past the request
+    pub fn push_tag(
+        &mut self,
+        tag: Tag,
+        request: &[u32],
+        response_len: usize,
+    ) -> Result<(), Error> {
+        let value_len = cmp::max(request.len(), (response_len + 3) / 4);
+        let end = self.len + TAG_HEADER_LEN + value_len;
+
+        // Leave room for the end tag and padding
+        if padded_len(end + 1) > self.buffer.len() {
+            return Err(Error::BufferTooSmall);
+        }
+
+        let words = &mut self.buffer[self.len..end];
+        let (header, value) = words.split_at_mut(TAG_HEADER_LEN);
+        let (req, rest) = value.split_at_mut(request.len());
+
+        header[0] = tag.into();
+        header[1] = (value_len * 4) as u32;
+        header[2] = (request.len() * 4) as u32;
+        req.copy_from_slice(request);
+        for w in rest.iter_mut() {
+            *w = 0;
+        }
+
+        self.len = end;
+
+        Ok(())
+    }
+
+    /// Writes the end tag, padding and the message header,
+    /// returns the message size in bytes
+    pub fn finish(&mut self) -> usize {
+        let len = padded_len(self.len + 1);
+
+        self.buffer[0] = (len * 4) as u32;
+        self.buffer[1] = REQUEST;
+        self.buffer[self.len] = Tag::Last.into();
+        for w in self.buffer[self.len + 1..len].iter_mut() {
+            *w = 0;
+        }
+
+        len * 4
+    }
+}
+
+fn padded_len(len: usize) -> usize {
+    (len + ALIGN_LEN - 1) & !(ALIGN_LEN - 1)
+}
+
+macro_rules! tuple_constructors {
+    ($($T:ident: $idx:tt),+) => {
+        /// Batches several commands into one message
+        impl<$($T),+> MailboxMsgBufferConstructor for ($($T,)+)
+        where
+            $($T: MailboxMsgBufferConstructor,)+
+        {
+            fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+                $(self.$idx.construct_buffer(msg)?;)+
+                Ok(())
+            }
+        }
+    }
+}
+
+tuple_constructors!(A: 0, B: 1);
+tuple_constructors!(A: 0, B: 1, C: 2);
+tuple_constructors!(A: 0, B: 1, C: 2, D: 3);
+tuple_constructors!(A: 0, B: 1, C: 2, D: 3, E: 4);
+tuple_constructors!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
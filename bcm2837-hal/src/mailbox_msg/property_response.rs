@@ -0,0 +1,172 @@
+//! Property channel response parsing
+
+use mailbox::Error;
+
+use super::PropertyTagResp;
+use super::Tag;
+
+/// Set in a tag's request/response code once the firmware has
+/// processed it, the lower bits hold the response value length in bytes
+pub const TAG_RESPONSE: u32 = 0x8000_0000;
+
+/// Words in the message header, buffer size and request/response code
+const HEADER_LEN: usize = 2;
+
+/// Words in a tag header, tag, value buffer size and request/response code
+const TAG_HEADER_LEN: usize = 3;
+
+/// A response tag and its value
+#[derive(Debug, Copy, Clone)]
+pub struct RespTag<'a> {
+    pub tag: Tag,
+    /// Response value, sized to the response length the firmware returned
+    pub value: &'a [u32],
+}
+
+/// Property channel response, validates the tags of the reply as it
+/// walks them
+#[derive(Debug, Copy, Clone)]
+pub struct PropertyResponse<'a> {
+    buffer: &'a [u32],
+}
+
+impl<'a> PropertyResponse<'a> {
+    /// The buffer must start with the message header, the message size
+    /// must fit the buffer
+    pub fn new(buffer: &'a [u32]) -> Result<Self, Error> {
+        if buffer.len() < HEADER_LEN {
+            return Err(Error::TruncatedResponse);
+        }
+
+        let len = buffer[0] as usize / 4;
+
+        if (len < HEADER_LEN) || (len > buffer.len()) {
+            return Err(Error::TruncatedResponse);
+        }
+
+        Ok(PropertyResponse {
+            buffer: &buffer[..len],
+        })
+    }
+
+    /// Iterates over every tag in the response, each one is checked for
+    /// the response bit and a value that fits its value buffer
+    pub fn tags(&self) -> RespTags<'a> {
+        RespTags {
+            buffer: self.buffer,
+            index: HEADER_LEN,
+            done: false,
+        }
+    }
+
+    /// Returns the value of the first tag matching `tag`, the firmware
+    /// must have returned at least `len` bytes
+    pub fn value(&self, tag: Tag, len: usize) -> Result<&'a [u32], Error> {
+        let mut tags = self.tags();
+
+        while let Some((t, result)) = tags.next_tag() {
+            match t {
+                // Errors of other tags don't matter
+                Some(t) if t != tag => continue,
+                Some(_) => {
+                    let value = result?.value;
+                    return if (value.len() * 4) < len {
+                        Err(Error::TruncatedResponse)
+                    } else {
+                        Ok(value)
+                    };
+                }
+                None => return result.map(|t| t.value),
+            }
+        }
+
+        Err(Error::MissingTag(tag))
+    }
+
+    /// Parses the first tag matching `T::TAG`
+    pub fn get<T: PropertyTagResp>(&self) -> Result<T, Error> {
+        let value = self.value(T::TAG, T::VALUE_LEN)?;
+        T::parse(value)
+    }
+
+    /// Returns the first error of any tag
+    pub fn check(&self) -> Result<(), Error> {
+        for t in self.tags() {
+            t?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Iterator over the tags of a `PropertyResponse`
+pub struct RespTags<'a> {
+    buffer: &'a [u32],
+    index: usize,
+    done: bool,
+}
+
+impl<'a> RespTags<'a> {
+    /// Returns the next tag result, along with its tag when the tag
+    /// header could be read
+    fn next_tag(&mut self) -> Option<(Option<Tag>, Result<RespTag<'a>, Error>)> {
+        if self.done {
+            return None;
+        }
+
+        let index = self.index;
+
+        // End tag
+        if self.buffer.get(index) == Some(&Tag::Last.into()) {
+            self.done = true;
+            return None;
+        }
+
+        let value_index = index + TAG_HEADER_LEN;
+
+        // The layout can't be trusted past a truncated tag, stop here
+        if value_index > self.buffer.len() {
+            self.done = true;
+            return Some((None, Err(Error::TruncatedResponse)));
+        }
+
+        let tag = Tag::from(self.buffer[index]);
+        let value_buffer_len = (self.buffer[index + 1] as usize + 3) / 4;
+        let code = self.buffer[index + 2];
+
+        if (value_index + value_buffer_len) > self.buffer.len() {
+            self.done = true;
+            return Some((None, Err(Error::TruncatedResponse)));
+        }
+
+        self.index = value_index + value_buffer_len;
+
+        if (code & TAG_RESPONSE) == 0 {
+            return Some((Some(tag), Err(Error::TagNotAcknowledged(tag))));
+        }
+
+        // The firmware returns the length it needed when the value
+        // buffer is too small
+        let value_len = ((code & !TAG_RESPONSE) as usize + 3) / 4;
+
+        if value_len > value_buffer_len {
+            return Some((Some(tag), Err(Error::TruncatedResponse)));
+        }
+
+        Some((
+            Some(tag),
+            Ok(RespTag {
+                tag,
+                value: &self.buffer[value_index..value_index + value_len],
+            }),
+        ))
+    }
+}
+
+impl<'a> Iterator for RespTags<'a> {
+    type Item = Result<RespTag<'a>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_tag().map(|(_, result)| result)
+    }
+}
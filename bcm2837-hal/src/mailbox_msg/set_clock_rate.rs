@@ -0,0 +1,50 @@
+use mailbox::Error;
+use time::Hertz;
+
+use super::clock_id::{parse_clock_id, ClockId};
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+
+pub const RESP_LEN: usize = 8;
+
+#[derive(Debug, Copy, Clone)]
+pub struct SetClockRateCmd {
+    pub id: ClockId,
+    pub rate: Hertz,
+    /// Don't let the firmware raise other clocks, the rate is set as is
+    pub skip_setting_turbo: bool,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SetClockRateResp {
+    pub id: ClockId,
+    pub rate: Hertz,
+}
+
+impl MailboxMsgBufferConstructor for SetClockRateCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(
+            Tag::SetClockRate,
+            &[
+                self.id.into(),
+                self.rate.0,
+                if self.skip_setting_turbo { 1 } else { 0 },
+            ],
+            RESP_LEN,
+        )
+    }
+}
+
+impl PropertyTagResp for SetClockRateResp {
+    const TAG: Tag = Tag::SetClockRate;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(SetClockRateResp {
+            id: parse_clock_id(value[0], Self::TAG)?,
+            rate: Hertz(value[1]),
+        })
+    }
+}
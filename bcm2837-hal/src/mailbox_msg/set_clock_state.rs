@@ -0,0 +1,46 @@
+use mailbox::Error;
+
+use super::clock_id::{parse_clock_id, ClockId};
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+use super::{CLOCK_STATE_NOT_EXISTS, CLOCK_STATE_ON};
+
+pub const RESP_LEN: usize = 8;
+
+#[derive(Debug, Copy, Clone)]
+pub struct SetClockStateCmd {
+    pub id: ClockId,
+    pub on: bool,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SetClockStateResp {
+    pub id: ClockId,
+    pub on: bool,
+    pub exists: bool,
+}
+
+impl MailboxMsgBufferConstructor for SetClockStateCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(
+            Tag::SetClockState,
+            &[self.id.into(), if self.on { CLOCK_STATE_ON } else { 0 }],
+            RESP_LEN,
+        )
+    }
+}
+
+impl PropertyTagResp for SetClockStateResp {
+    const TAG: Tag = Tag::SetClockState;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(SetClockStateResp {
+            id: parse_clock_id(value[0], Self::TAG)?,
+            on: (value[1] & CLOCK_STATE_ON) != 0,
+            exists: (value[1] & CLOCK_STATE_NOT_EXISTS) == 0,
+        })
+    }
+}
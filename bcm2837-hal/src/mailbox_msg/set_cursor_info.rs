@@ -0,0 +1,58 @@
+use mailbox::Error;
+use pmem::PMem;
+
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+
+pub const RESP_LEN: usize = 4;
+
+/// Sets the hardware cursor image
+#[derive(Debug, Copy, Clone)]
+pub struct SetCursorInfoCmd {
+    pub width: u32,
+    pub height: u32,
+    pub hotspot_x: u32,
+    pub hotspot_y: u32,
+    /// `width * height` 32 bit ARGB pixels, read by the VideoCore
+    pub pixels: PMem,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SetCursorInfoResp {
+    pub is_valid: bool,
+}
+
+impl MailboxMsgBufferConstructor for SetCursorInfoCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        if self.pixels.size() < (self.width * self.height * 4) as usize {
+            return Err(Error::InvalidRequest(Tag::SetCursorInfo));
+        }
+
+        msg.push_tag(
+            Tag::SetCursorInfo,
+            &[
+                self.width,
+                self.height,
+                // Unused
+                0,
+                self.pixels.bus_paddr(),
+                self.hotspot_x,
+                self.hotspot_y,
+            ],
+            RESP_LEN,
+        )
+    }
+}
+
+impl PropertyTagResp for SetCursorInfoResp {
+    const TAG: Tag = Tag::SetCursorInfo;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(SetCursorInfoResp {
+            is_valid: value[0] == 0,
+        })
+    }
+}
@@ -1,10 +1,11 @@
+use mailbox::Error;
+
 use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
 use super::Tag;
-use super::MAILBOX_BUFFER_LEN;
-use super::REQUEST;
 
-pub const CMD_LEN: u32 = 16;
-pub const RESP_LEN: u32 = 4;
+pub const RESP_LEN: usize = 4;
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum CursorCoord {
@@ -26,26 +27,28 @@ pub struct SetCursorStateResp {
 }
 
 impl MailboxMsgBufferConstructor for SetCursorStateCmd {
-    fn construct_buffer(&self, buffer: &mut [u32; MAILBOX_BUFFER_LEN]) {
-        buffer[0] = 10 * 4;
-        buffer[1] = REQUEST;
-        buffer[2] = Tag::SetCursorState.into();
-        buffer[3] = CMD_LEN;
-        buffer[4] = RESP_LEN;
-        buffer[5] = if self.enable == true { 1 } else { 0 };
-        buffer[6] = self.x;
-        buffer[7] = self.y;
-        buffer[8] = self.coord.into();
-        buffer[9] = Tag::Last.into();
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(
+            Tag::SetCursorState,
+            &[
+                if self.enable == true { 1 } else { 0 },
+                self.x,
+                self.y,
+                self.coord.into(),
+            ],
+            RESP_LEN,
+        )
     }
 }
 
-impl From<&[u32; MAILBOX_BUFFER_LEN]> for SetCursorStateResp {
-    fn from(buffer: &[u32; MAILBOX_BUFFER_LEN]) -> SetCursorStateResp {
-        assert_eq!(buffer[2], Tag::SetCursorState.into());
-        SetCursorStateResp {
-            is_valid: if buffer[5] == 0 { true } else { false },
-        }
+impl PropertyTagResp for SetCursorStateResp {
+    const TAG: Tag = Tag::SetCursorState;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(SetCursorStateResp {
+            is_valid: if value[0] == 0 { true } else { false },
+        })
     }
 }
 
@@ -0,0 +1,53 @@
+use mailbox::Error;
+
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+
+pub const RESP_LEN: usize = 4;
+
+/// Number of palette entries
+pub const PALETTE_LEN: usize = 256;
+
+/// Sets palette entries of an 8 bit depth framebuffer
+#[derive(Debug, Copy, Clone)]
+pub struct SetPaletteCmd<'a> {
+    /// First entry to set
+    pub offset: u32,
+    /// Entries as 0x00BBGGRR
+    pub colors: &'a [u32],
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SetPaletteResp;
+
+impl<'a> MailboxMsgBufferConstructor for SetPaletteCmd<'a> {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        let len = self.colors.len();
+        if (len == 0) || ((self.offset as usize + len) > PALETTE_LEN) {
+            return Err(Error::InvalidRequest(Tag::SetPalette));
+        }
+
+        let mut request = [0; 2 + PALETTE_LEN];
+        request[0] = self.offset;
+        request[1] = len as u32;
+        request[2..2 + len].copy_from_slice(self.colors);
+
+        msg.push_tag(Tag::SetPalette, &request[..2 + len], RESP_LEN)
+    }
+}
+
+impl PropertyTagResp for SetPaletteResp {
+    const TAG: Tag = Tag::SetPalette;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    /// The firmware rejected the palette if the value is non-zero
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        if value[0] != 0 {
+            return Err(Error::InvalidResponse(Tag::SetPalette));
+        }
+
+        Ok(SetPaletteResp)
+    }
+}
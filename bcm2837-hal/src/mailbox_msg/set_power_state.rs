@@ -0,0 +1,52 @@
+use mailbox::Error;
+
+use super::power_device::{parse_power_device, PowerDevice};
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+use super::{POWER_STATE_NOT_EXISTS, POWER_STATE_ON, POWER_STATE_WAIT};
+
+pub const RESP_LEN: usize = 8;
+
+#[derive(Debug, Copy, Clone)]
+pub struct SetPowerStateCmd {
+    pub device: PowerDevice,
+    pub on: bool,
+    /// Don't respond until the power is stable
+    pub wait: bool,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SetPowerStateResp {
+    pub device: PowerDevice,
+    pub on: bool,
+    pub exists: bool,
+}
+
+impl MailboxMsgBufferConstructor for SetPowerStateCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        let mut state = 0;
+        if self.on {
+            state |= POWER_STATE_ON;
+        }
+        if self.wait {
+            state |= POWER_STATE_WAIT;
+        }
+
+        msg.push_tag(Tag::SetPowerState, &[self.device.into(), state], RESP_LEN)
+    }
+}
+
+impl PropertyTagResp for SetPowerStateResp {
+    const TAG: Tag = Tag::SetPowerState;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(SetPowerStateResp {
+            device: parse_power_device(value[0], Self::TAG)?,
+            on: (value[1] & POWER_STATE_ON) != 0,
+            exists: (value[1] & POWER_STATE_NOT_EXISTS) == 0,
+        })
+    }
+}
@@ -0,0 +1,40 @@
+use mailbox::Error;
+
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::PropertyTagResp;
+use super::Tag;
+
+pub const RESP_LEN: usize = 8;
+
+/// Moves the displayed region within the virtual framebuffer, used
+/// for page flipping
+#[derive(Debug, Copy, Clone)]
+pub struct SetVirtOffsetCmd {
+    pub x: u32,
+    pub y: u32,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SetVirtOffsetResp {
+    pub x: u32,
+    pub y: u32,
+}
+
+impl MailboxMsgBufferConstructor for SetVirtOffsetCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::SetVirtOffset, &[self.x, self.y], RESP_LEN)
+    }
+}
+
+impl PropertyTagResp for SetVirtOffsetResp {
+    const TAG: Tag = Tag::SetVirtOffset;
+    const VALUE_LEN: usize = RESP_LEN;
+
+    fn parse(value: &[u32]) -> Result<Self, Error> {
+        Ok(SetVirtOffsetResp {
+            x: value[0],
+            y: value[1],
+        })
+    }
+}
@@ -0,0 +1,46 @@
+use mailbox::Error;
+
+use super::Tag;
+
+/// Returned in place of the value for an invalid voltage id
+const VOLTAGE_INVALID: u32 = 0x8000_0000;
+
+/// Voltage domains managed by the firmware
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum VoltageId {
+    Core,
+    SdramC,
+    SdramP,
+    SdramI,
+}
+
+impl From<VoltageId> for u32 {
+    fn from(id: VoltageId) -> u32 {
+        match id {
+            VoltageId::Core => 1,
+            VoltageId::SdramC => 2,
+            VoltageId::SdramP => 3,
+            VoltageId::SdramI => 4,
+        }
+    }
+}
+
+/// Parses the voltage id of a `tag` response
+pub fn parse_voltage_id(id: u32, tag: Tag) -> Result<VoltageId, Error> {
+    match id {
+        1 => Ok(VoltageId::Core),
+        2 => Ok(VoltageId::SdramC),
+        3 => Ok(VoltageId::SdramP),
+        4 => Ok(VoltageId::SdramI),
+        _ => Err(Error::InvalidResponse(tag)),
+    }
+}
+
+/// Parses the voltage value of a `tag` response
+pub fn parse_voltage(value: u32, tag: Tag) -> Result<i32, Error> {
+    if value == VOLTAGE_INVALID {
+        Err(Error::InvalidResponse(tag))
+    } else {
+        Ok(value as i32)
+    }
+}
@@ -0,0 +1,18 @@
+use mailbox::Error;
+
+use super::MailboxMsgBufferConstructor;
+use super::PropertyMessage;
+use super::Tag;
+
+pub const RESP_LEN: usize = 4;
+
+/// Blocks the response until the next vertical sync, batch it in front
+/// of a `SetVirtOffsetCmd` for a tear-free page flip
+#[derive(Debug, Copy, Clone)]
+pub struct WaitForVsyncCmd;
+
+impl MailboxMsgBufferConstructor for WaitForVsyncCmd {
+    fn construct_buffer(&self, msg: &mut PropertyMessage) -> Result<(), Error> {
+        msg.push_tag(Tag::WaitForVsync, &[0], RESP_LEN)
+    }
+}
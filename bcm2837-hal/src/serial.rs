@@ -2,23 +2,322 @@
 //! UART0 and UART1 are significantly different so they both
 //! have hand implementations rather than using a macro
 //!
-//! TODO - UART0
+//! UART1 can also be split into interrupt-driven, ring-buffered
+//! Tx/Rx halves, see `Serial::into_interrupt_driven`
 
 // TODO - detangle namespace
 use bcm2837::gpio::*;
+use bcm2837::uart0::*;
 use bcm2837::uart1::*;
+use core::sync::atomic::{AtomicBool, Ordering};
 use cortex_a::asm;
 use hal::prelude::*;
 use hal::serial;
+use heapless::consts::U256;
+use heapless::spsc::{Consumer, Producer, Queue};
 use void::Void;
 
-pub struct Serial<UART> {
+use clocks::Clocks;
+use gpio::{Alternate, Pin14, Pin15, Pin16, Pin17, AF0, AF3};
+use time::Bps;
+
+/// Serial error
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Error {
+    /// Framing error
+    Framing,
+    /// Parity error
+    Parity,
+    /// RX FIFO overrun
+    Overrun,
+    /// Break condition
+    Break,
+    /// Baud rate can't be generated within tolerance
+    BaudRate,
+    #[doc(hidden)]
+    _Extensible,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum WordLength {
+    DataBits5,
+    DataBits6,
+    DataBits7,
+    DataBits8,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Parity {
+    ParityNone,
+    ParityEven,
+    ParityOdd,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum StopBits {
+    Stop1,
+    Stop2,
+}
+
+/// FIFO level at which the TX/RX interrupts are raised
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FifoLevel {
+    OneEighth,
+    OneQuarter,
+    OneHalf,
+    ThreeQuarters,
+    SevenEighths,
+}
+
+/// UART0 line and FIFO configuration
+#[derive(Debug, Copy, Clone)]
+pub struct Config {
+    pub word_length: WordLength,
+    pub parity: Parity,
+    pub stop_bits: StopBits,
+    pub tx_fifo_level: FifoLevel,
+    pub rx_fifo_level: FifoLevel,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            word_length: WordLength::DataBits8,
+            parity: Parity::ParityNone,
+            stop_bits: StopBits::Stop1,
+            tx_fifo_level: FifoLevel::OneHalf,
+            rx_fifo_level: FifoLevel::OneHalf,
+        }
+    }
+}
+
+/// TX and RX pins, optionally with CTS and RTS
+pub trait Pins<UART> {
+    /// Hardware flow control is enabled when the CTS and RTS pins are given
+    const FLOW_CONTROL: bool;
+}
+
+impl Pins<UART0> for (Pin14<Alternate<AF0>>, Pin15<Alternate<AF0>>) {
+    const FLOW_CONTROL: bool = false;
+}
+
+impl Pins<UART0>
+    for (
+        Pin14<Alternate<AF0>>,
+        Pin15<Alternate<AF0>>,
+        Pin16<Alternate<AF3>>,
+        Pin17<Alternate<AF3>>,
+    )
+{
+    const FLOW_CONTROL: bool = true;
+}
+
+pub struct Serial<UART, PINS = ()> {
     uart: UART,
+    pins: PINS,
+    baud_rate: Bps,
+}
+
+/// Largest baud rate error, in tenths of a percent, accepted for the
+/// mini UART
+const UART1_BAUD_TOLERANCE_PERMILLE: u32 = 20;
+
+impl<PINS> Serial<UART0, PINS>
+where
+    PINS: Pins<UART0>,
+{
+    /// Configures UART0 for 8N1 at the given baud rate
+    pub fn uart0(uart: UART0, baud_rate: Bps, pins: PINS, clocks: Clocks) -> Result<Self, Error> {
+        let (ibrd, fbrd) = uart0_divisors(clocks.uartclk().0, baud_rate.0)?;
+
+        // Disable while configuring
+        uart.CR.set(0);
+        uart.IMSC.set(0);
+        uart.ICR.set(0x7FF);
+
+        uart.IBRD.write(IBRD::IBRD.val(ibrd));
+        uart.FBRD.write(FBRD::FBRD.val(fbrd));
+
+        let mut serial = Serial {
+            uart,
+            pins,
+            baud_rate,
+        };
+
+        // Writing LCRH latches the baud rate divisors
+        serial.set_config(Config::default());
+
+        Ok(serial)
+    }
+
+    /// Reconfigures the line control and FIFO levels, waits for any
+    /// pending transmission to complete
+    pub fn set_config(&mut self, config: Config) {
+        while self.uart.FR.is_set(FR::BUSY) {}
+
+        self.uart.CR.set(0);
+
+        // Flush the FIFOs
+        self.uart.LCRH.modify(LCRH::FEN::CLEAR);
+
+        let wlen = match config.word_length {
+            WordLength::DataBits5 => LCRH::WLEN::FiveBit,
+            WordLength::DataBits6 => LCRH::WLEN::SixBit,
+            WordLength::DataBits7 => LCRH::WLEN::SevenBit,
+            WordLength::DataBits8 => LCRH::WLEN::EightBit,
+        };
+
+        let parity = match config.parity {
+            Parity::ParityNone => LCRH::PEN::CLEAR,
+            Parity::ParityEven => LCRH::PEN::SET + LCRH::EPS::SET,
+            Parity::ParityOdd => LCRH::PEN::SET + LCRH::EPS::CLEAR,
+        };
+
+        let stop_bits = match config.stop_bits {
+            StopBits::Stop1 => LCRH::STP2::CLEAR,
+            StopBits::Stop2 => LCRH::STP2::SET,
+        };
+
+        self.uart
+            .LCRH
+            .write(wlen + parity + stop_bits + LCRH::FEN::SET);
+
+        let txiflsel = match config.tx_fifo_level {
+            FifoLevel::OneEighth => IFLS::TXIFLSEL::OneEighth,
+            FifoLevel::OneQuarter => IFLS::TXIFLSEL::OneQuarter,
+            FifoLevel::OneHalf => IFLS::TXIFLSEL::OneHalf,
+            FifoLevel::ThreeQuarters => IFLS::TXIFLSEL::ThreeQuarters,
+            FifoLevel::SevenEighths => IFLS::TXIFLSEL::SevenEighths,
+        };
+
+        let rxiflsel = match config.rx_fifo_level {
+            FifoLevel::OneEighth => IFLS::RXIFLSEL::OneEighth,
+            FifoLevel::OneQuarter => IFLS::RXIFLSEL::OneQuarter,
+            FifoLevel::OneHalf => IFLS::RXIFLSEL::OneHalf,
+            FifoLevel::ThreeQuarters => IFLS::RXIFLSEL::ThreeQuarters,
+            FifoLevel::SevenEighths => IFLS::RXIFLSEL::SevenEighths,
+        };
+
+        self.uart.IFLS.write(txiflsel + rxiflsel);
+
+        let flow_control = if PINS::FLOW_CONTROL {
+            CR::CTSEN::SET + CR::RTSEN::SET
+        } else {
+            CR::CTSEN::CLEAR + CR::RTSEN::CLEAR
+        };
+
+        self.uart
+            .CR
+            .write(CR::UARTEN::SET + CR::TXE::SET + CR::RXE::SET + flow_control);
+    }
+
+    pub fn free(self) -> (UART0, PINS) {
+        while self.uart.FR.is_set(FR::BUSY) {}
+        self.uart.CR.set(0);
+        (self.uart, self.pins)
+    }
+}
+
+/// Returns the (integer, fractional) baud rate divisors,
+/// BAUDDIV = UARTCLK / (16 * baud rate), with a 6 bit fraction
+fn uart0_divisors(uartclk: u32, baud_rate: u32) -> Result<(u32, u32), Error> {
+    if baud_rate == 0 {
+        return Err(Error::BaudRate);
+    }
+
+    // 64 * BAUDDIV, rounded to the nearest
+    let div = ((4 * uartclk as u64) + (baud_rate as u64 / 2)) / baud_rate as u64;
+
+    let ibrd = (div >> 6) as u32;
+    let fbrd = (div & 0x3F) as u32;
+
+    if (ibrd == 0) || (ibrd > 0xFFFF) {
+        return Err(Error::BaudRate);
+    }
+
+    Ok((ibrd, fbrd))
+}
+
+impl<PINS> serial::Read<u8> for Serial<UART0, PINS> {
+    type Error = Error;
+
+    fn read(&mut self) -> nb::Result<u8, Error> {
+        if self.uart.FR.is_set(FR::RXFE) {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        // Error status is valid for the character just read
+        let data = self.uart.DR.read(DR::DATA) as u8;
+
+        let err = if self.uart.RSRECR.is_set(RSRECR::OE) {
+            Some(Error::Overrun)
+        } else if self.uart.RSRECR.is_set(RSRECR::BE) {
+            Some(Error::Break)
+        } else if self.uart.RSRECR.is_set(RSRECR::PE) {
+            Some(Error::Parity)
+        } else if self.uart.RSRECR.is_set(RSRECR::FE) {
+            Some(Error::Framing)
+        } else {
+            None
+        };
+
+        match err {
+            Some(e) => {
+                // Any write clears the error flags
+                self.uart.RSRECR.set(0);
+                Err(nb::Error::Other(e))
+            }
+            None => Ok(data),
+        }
+    }
+}
+
+impl<PINS> serial::Write<u8> for Serial<UART0, PINS> {
+    // No errors
+    type Error = Void;
+
+    fn flush(&mut self) -> nb::Result<(), Void> {
+        if self.uart.FR.is_set(FR::TXFE) && !self.uart.FR.is_set(FR::BUSY) {
+            Ok(())
+        } else {
+            Err(nb::Error::WouldBlock)
+        }
+    }
+
+    fn write(&mut self, byte: u8) -> nb::Result<(), Void> {
+        if self.uart.FR.is_set(FR::TXFF) {
+            Err(nb::Error::WouldBlock)
+        } else {
+            self.uart.DR.set(byte as _);
+            Ok(())
+        }
+    }
+}
+
+impl<PINS> ::core::fmt::Write for Serial<UART0, PINS> {
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        for b in s.bytes() {
+            // convert '\n' to '\r\n'
+            if b as char == '\n' {
+                block!(self.write('\r' as _)).ok();
+            }
+            block!(self.write(b)).ok();
+        }
+        Ok(())
+    }
 }
 
-// TODO - time bits, Bps, etc
 impl Serial<UART1> {
-    pub fn uart1(uart: UART1, _baud_rate: u32, gpio: &mut GPIO) -> Self {
+    /// Configures the mini UART for 8N1 at the given baud rate, which is
+    /// derived from the core clock
+    pub fn uart1(
+        uart: UART1,
+        baud_rate: Bps,
+        gpio: &mut GPIO,
+        clocks: Clocks,
+    ) -> Result<Self, Error> {
+        let baud_reg = uart1_baud_reg(clocks.apbclk().0, baud_rate.0)?;
+
         uart.AUX_ENABLES.modify(AUX_ENABLES::MINI_UART_ENABLE::SET);
         uart.AUX_MU_IER.set(0);
         uart.AUX_MU_CNTL.set(0);
@@ -26,7 +325,7 @@ impl Serial<UART1> {
         uart.AUX_MU_MCR.set(0);
         uart.AUX_MU_IER.set(0);
         uart.AUX_MU_IIR.write(AUX_MU_IIR::FIFO_CLEAR::All);
-        uart.AUX_MU_BAUD.write(AUX_MU_BAUD::RATE.val(270)); // 115200 baud
+        uart.AUX_MU_BAUD.write(AUX_MU_BAUD::RATE.val(baud_reg));
 
         // map UART1 to GPIO pins
         gpio.GPFSEL1
@@ -48,7 +347,74 @@ impl Serial<UART1> {
         uart.AUX_MU_CNTL
             .write(AUX_MU_CNTL::RX_EN::Enabled + AUX_MU_CNTL::TX_EN::Enabled);
 
-        Serial { uart }
+        Ok(Serial {
+            uart,
+            pins: (),
+            baud_rate,
+        })
+    }
+
+    /// Changes the baud rate
+    pub fn set_baud_rate(&mut self, baud_rate: Bps, clocks: Clocks) -> Result<(), Error> {
+        let baud_reg = uart1_baud_reg(clocks.apbclk().0, baud_rate.0)?;
+
+        // Let any pending transmission complete at the old rate
+        while !self.uart.AUX_MU_LSR.is_set(AUX_MU_LSR::TX_IDLE) {}
+
+        self.uart.AUX_MU_BAUD.write(AUX_MU_BAUD::RATE.val(baud_reg));
+        self.baud_rate = baud_rate;
+
+        Ok(())
+    }
+
+    /// Recomputes the baud rate divisor, the mini UART clock follows the
+    /// core clock so this must be called when it changes
+    pub fn set_clocks(&mut self, clocks: Clocks) -> Result<(), Error> {
+        let baud_rate = self.baud_rate;
+        self.set_baud_rate(baud_rate, clocks)
+    }
+
+    pub fn baud_rate(&self) -> Bps {
+        self.baud_rate
+    }
+
+    /// Enables the RX interrupt and splits the serial into
+    /// ring-buffered transmit and receive halves, plus the interrupt
+    /// handler that moves bytes between the buffers and the FIFOs
+    pub fn into_interrupt_driven<'a>(
+        self,
+        buffers: &'a mut SerialBuffers,
+    ) -> (Tx<'a>, Rx<'a>, SerialIrq<'a>) {
+        let SerialBuffers {
+            ref mut rx,
+            ref mut tx,
+            ref rx_overrun,
+            ref tx_busy,
+        } = *buffers;
+
+        let (rx_prod, rx_cons) = rx.split();
+        let (tx_prod, tx_cons) = tx.split();
+
+        uart1_enable_rx_int(&self.uart);
+
+        (
+            Tx {
+                uart: self.uart,
+                queue: tx_prod,
+                busy: tx_busy,
+            },
+            Rx {
+                queue: rx_cons,
+                overrun: rx_overrun,
+            },
+            SerialIrq {
+                uart: self.uart,
+                rx: rx_prod,
+                tx: tx_cons,
+                rx_overrun,
+                tx_busy,
+            },
+        )
     }
 
     pub fn free(self) -> UART1 {
@@ -56,6 +422,35 @@ impl Serial<UART1> {
     }
 }
 
+/// Returns the mini UART baud rate register value,
+/// baud rate = core clock / (8 * (reg + 1))
+fn uart1_baud_reg(core_clock: u32, baud_rate: u32) -> Result<u32, Error> {
+    if baud_rate == 0 {
+        return Err(Error::BaudRate);
+    }
+
+    // Round to the nearest divisor, in u64 so large baud rates can't overflow
+    let div = (u64::from(core_clock) + (4 * u64::from(baud_rate))) / (8 * u64::from(baud_rate));
+
+    if (div == 0) || (div > 0x1_0000) {
+        return Err(Error::BaudRate);
+    }
+
+    let div = div as u32;
+    let actual = core_clock / (8 * div);
+    let delta = if actual > baud_rate {
+        actual - baud_rate
+    } else {
+        baud_rate - actual
+    };
+
+    if (delta as u64 * 1000) > (baud_rate as u64 * UART1_BAUD_TOLERANCE_PERMILLE as u64) {
+        return Err(Error::BaudRate);
+    }
+
+    Ok(div - 1)
+}
+
 impl serial::Read<u8> for Serial<UART1> {
     // No errors
     type Error = Void;
@@ -110,3 +505,190 @@ impl ::core::fmt::Write for Serial<UART1> {
         Ok(())
     }
 }
+
+/// Capacity, in bytes, of each of the interrupt-driven ring buffers
+pub type BufferCapacity = U256;
+
+/// Ring buffers shared by the interrupt-driven `Tx`, `Rx` and
+/// `SerialIrq` halves
+pub struct SerialBuffers {
+    rx: Queue<u8, BufferCapacity>,
+    tx: Queue<u8, BufferCapacity>,
+    rx_overrun: AtomicBool,
+    tx_busy: AtomicBool,
+}
+
+impl SerialBuffers {
+    pub fn new() -> Self {
+        SerialBuffers {
+            rx: Queue::new(),
+            tx: Queue::new(),
+            rx_overrun: AtomicBool::new(false),
+            tx_busy: AtomicBool::new(false),
+        }
+    }
+}
+
+/// Enables only the RX interrupt
+fn uart1_enable_rx_int(uart: &UART1) {
+    uart.AUX_MU_IER
+        .write(AUX_MU_IER::INT_EN.val(0b11) + AUX_MU_IER::RX_INT::SET);
+}
+
+/// Enables both the RX and TX interrupts
+fn uart1_enable_rx_tx_int(uart: &UART1) {
+    uart.AUX_MU_IER
+        .write(AUX_MU_IER::INT_EN.val(0b11) + AUX_MU_IER::RX_INT::SET + AUX_MU_IER::TX_INT::SET);
+}
+
+/// Events reported by `SerialIrq::handle_interrupt`, used to wake
+/// threads waiting on the `Rx` and `Tx` halves
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct IrqEvents {
+    /// Bytes were moved into the receive buffer
+    pub rx: bool,
+    /// Bytes were moved out of the transmit buffer
+    pub tx: bool,
+}
+
+/// Interrupt handler half of an interrupt-driven UART1
+///
+/// Intended to be owned by the thread bound to the AUX IRQ
+/// notification: wait on the notification, call `handle_interrupt`,
+/// then acknowledge the IRQ handler
+pub struct SerialIrq<'a> {
+    uart: UART1,
+    rx: Producer<'a, u8, BufferCapacity>,
+    tx: Consumer<'a, u8, BufferCapacity>,
+    rx_overrun: &'a AtomicBool,
+    tx_busy: &'a AtomicBool,
+}
+
+unsafe impl<'a> Send for SerialIrq<'a> {}
+
+impl<'a> SerialIrq<'a> {
+    /// Drains the receive FIFO into the receive buffer and refills the
+    /// transmit FIFO from the transmit buffer
+    pub fn handle_interrupt(&mut self) -> IrqEvents {
+        let mut events = IrqEvents::default();
+
+        // The receive FIFO only holds 8 bytes, always empty it first
+        loop {
+            // Reading clears the overrun flag, only read it once
+            let lsr = self.uart.AUX_MU_LSR.extract();
+
+            if lsr.is_set(AUX_MU_LSR::RX_OVERRUN) {
+                self.rx_overrun.store(true, Ordering::Release);
+            }
+
+            if !lsr.is_set(AUX_MU_LSR::DATA_READY) {
+                break;
+            }
+
+            let data = self.uart.AUX_MU_IO.get() as u8;
+
+            if self.rx.enqueue(data).is_err() {
+                self.rx_overrun.store(true, Ordering::Release);
+            }
+
+            events.rx = true;
+        }
+
+        while self.uart.AUX_MU_LSR.is_set(AUX_MU_LSR::TX_EMPTY) {
+            if let Some(data) = self.tx.dequeue() {
+                self.uart.AUX_MU_IO.set(data as _);
+                events.tx = true;
+                continue;
+            }
+
+            // Nothing left to send, stop the TX interrupt, then check
+            // again in case the Tx half queued more bytes meanwhile
+            self.tx_busy.store(false, Ordering::Release);
+            uart1_enable_rx_int(&self.uart);
+
+            if !self.tx.ready() {
+                break;
+            }
+
+            self.tx_busy.store(true, Ordering::Release);
+            uart1_enable_rx_tx_int(&self.uart);
+        }
+
+        events
+    }
+}
+
+/// Transmit half of an interrupt-driven UART1
+pub struct Tx<'a> {
+    uart: UART1,
+    queue: Producer<'a, u8, BufferCapacity>,
+    busy: &'a AtomicBool,
+}
+
+unsafe impl<'a> Send for Tx<'a> {}
+
+impl<'a> serial::Write<u8> for Tx<'a> {
+    // No errors
+    type Error = Void;
+
+    fn flush(&mut self) -> nb::Result<(), Void> {
+        if !self.busy.load(Ordering::Acquire) && self.uart.AUX_MU_LSR.is_set(AUX_MU_LSR::TX_IDLE) {
+            Ok(())
+        } else {
+            Err(nb::Error::WouldBlock)
+        }
+    }
+
+    fn write(&mut self, byte: u8) -> nb::Result<(), Void> {
+        if self.queue.enqueue(byte).is_err() {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        // The interrupt handler disables the TX interrupt once the
+        // buffer drains
+        self.busy.store(true, Ordering::Release);
+        uart1_enable_rx_tx_int(&self.uart);
+
+        Ok(())
+    }
+}
+
+impl<'a> ::core::fmt::Write for Tx<'a> {
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        for b in s.bytes() {
+            // convert '\n' to '\r\n'
+            if b as char == '\n' {
+                block!(self.write('\r' as _)).ok();
+            }
+            block!(self.write(b)).ok();
+        }
+        Ok(())
+    }
+}
+
+/// Receive half of an interrupt-driven UART1
+pub struct Rx<'a> {
+    queue: Consumer<'a, u8, BufferCapacity>,
+    overrun: &'a AtomicBool,
+}
+
+unsafe impl<'a> Send for Rx<'a> {}
+
+impl<'a> serial::Read<u8> for Rx<'a> {
+    type Error = Error;
+
+    /// Reports `Error::Overrun` once if bytes were dropped, either by
+    /// the receive FIFO or because the receive buffer was full
+    fn read(&mut self) -> nb::Result<u8, Error> {
+        if self.overrun.swap(false, Ordering::AcqRel) {
+            return Err(nb::Error::Other(Error::Overrun));
+        }
+
+        match self.queue.dequeue() {
+            // convert carrige return to newline
+            Some(data) if data == '\r' as _ => Ok('\n' as _),
+            Some(data) => Ok(data),
+            None => Err(nb::Error::WouldBlock),
+        }
+    }
+}
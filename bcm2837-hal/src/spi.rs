@@ -0,0 +1,457 @@
+//! Serial Peripheral Interface
+//!
+//! SPI0 master, polled mode or DMA driven transfers
+
+use bcm2837::spi0::*;
+use hal::blocking::spi as blocking;
+use hal::spi::{FullDuplex, Mode, Phase, Polarity};
+use void::Void;
+
+use clocks::Clocks;
+use dma::{self, Buffer, DmaBuffer};
+use gpio::{Alternate, Pin10, Pin11, Pin9, AF0};
+use time::Hertz;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Error {
+    /// Transfer length is zero or larger than DLEN can hold
+    TransferLength,
+    /// A buffer is smaller than the transfer length
+    BufferTooSmall,
+    /// The TX DMA channel reported errors
+    TxDma,
+    /// The RX DMA channel reported errors
+    RxDma,
+    #[doc(hidden)]
+    _Extensible,
+}
+
+/// SCK pin marker
+pub trait SckPin<SPI> {}
+
+/// MISO pin marker
+pub trait MisoPin<SPI> {}
+
+/// MOSI pin marker
+pub trait MosiPin<SPI> {}
+
+impl SckPin<SPI0> for Pin11<Alternate<AF0>> {}
+impl MisoPin<SPI0> for Pin9<Alternate<AF0>> {}
+impl MosiPin<SPI0> for Pin10<Alternate<AF0>> {}
+
+/// SCK, MISO and MOSI pins
+pub trait Pins<SPI> {}
+
+impl<SPI, SCK, MISO, MOSI> Pins<SPI> for (SCK, MISO, MOSI)
+where
+    SCK: SckPin<SPI>,
+    MISO: MisoPin<SPI>,
+    MOSI: MosiPin<SPI>,
+{
+}
+
+/// Hardware chip select line
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ChipSelect {
+    /// CE0, GPIO 8
+    Cs0,
+    /// CE1, GPIO 7
+    Cs1,
+}
+
+/// Chip select polarity
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ChipSelectPolarity {
+    ActiveLow,
+    ActiveHigh,
+}
+
+/// Smallest clock divider, the divisor must be a multiple of 2
+const MIN_CDIV: u32 = 2;
+
+/// Largest clock divider, written to the register as 0
+const MAX_CDIV: u32 = 65536;
+
+pub struct Spi<SPI, PINS> {
+    spi: SPI,
+    pins: PINS,
+}
+
+impl<PINS> Spi<SPI0, PINS> {
+    /// Configures SPI0 as a master using CE0 (active low) as chip select
+    pub fn spi0<F>(spi: SPI0, pins: PINS, mode: Mode, freq: F, clocks: Clocks) -> Self
+    where
+        PINS: Pins<SPI0>,
+        F: Into<Hertz>,
+    {
+        // Reset the control register and clear both FIFOs
+        spi.CS.set(0);
+        spi.CS.write(CS::CLEAR::ClearTxRx);
+
+        let mut spi = Spi { spi, pins };
+
+        spi.set_mode(mode);
+        spi.set_chip_select(ChipSelect::Cs0, ChipSelectPolarity::ActiveLow);
+        spi.set_frequency(freq, clocks);
+
+        spi
+    }
+
+    /// Sets the clock polarity and phase
+    pub fn set_mode(&mut self, mode: Mode) {
+        let cpol = match mode.polarity {
+            Polarity::IdleLow => CS::CPOL::RestingLow,
+            Polarity::IdleHigh => CS::CPOL::RestingHigh,
+        };
+
+        let cpha = match mode.phase {
+            Phase::CaptureOnFirstTransition => CS::CPHA::Middle,
+            Phase::CaptureOnSecondTransition => CS::CPHA::Beginning,
+        };
+
+        self.spi.CS.modify(cpol + cpha);
+    }
+
+    /// Selects which chip select line is asserted during a transfer, and its
+    /// polarity
+    pub fn set_chip_select(&mut self, cs: ChipSelect, polarity: ChipSelectPolarity) {
+        match (cs, polarity) {
+            (ChipSelect::Cs0, ChipSelectPolarity::ActiveLow) => {
+                self.spi.CS.modify(CS::CS::CS_0 + CS::CSPOL0::ActiveLow)
+            }
+            (ChipSelect::Cs0, ChipSelectPolarity::ActiveHigh) => {
+                self.spi.CS.modify(CS::CS::CS_0 + CS::CSPOL0::ActiveHigh)
+            }
+            (ChipSelect::Cs1, ChipSelectPolarity::ActiveLow) => {
+                self.spi.CS.modify(CS::CS::CS_1 + CS::CSPOL1::ActiveLow)
+            }
+            (ChipSelect::Cs1, ChipSelectPolarity::ActiveHigh) => {
+                self.spi.CS.modify(CS::CS::CS_1 + CS::CSPOL1::ActiveHigh)
+            }
+        }
+    }
+
+    /// Sets the SPI clock to the closest frequency at or below `freq`,
+    /// zero selects the slowest clock
+    pub fn set_frequency<F>(&mut self, freq: F, clocks: Clocks)
+    where
+        F: Into<Hertz>,
+    {
+        let freq: Hertz = freq.into();
+        let apbclk = clocks.apbclk().0;
+
+        // Round up to the next even divisor so we never exceed the
+        // requested frequency
+        let mut cdiv = if freq.0 == 0 {
+            MAX_CDIV
+        } else {
+            apbclk / freq.0 + (apbclk % freq.0 != 0) as u32
+        };
+        cdiv += cdiv & 1;
+
+        let cdiv = if cdiv < MIN_CDIV {
+            MIN_CDIV
+        } else if cdiv >= MAX_CDIV {
+            0
+        } else {
+            cdiv
+        };
+
+        self.spi.CLK.write(CLK::CDIV.val(cdiv));
+    }
+
+    /// Waits for the current transfer to complete and deasserts chip select
+    pub fn end_transfer(&mut self) {
+        if self.spi.CS.is_set(CS::TA) {
+            while !self.spi.CS.is_set(CS::DONE) {}
+            self.spi.CS.modify(CS::TA::CLEAR);
+        }
+    }
+
+    /// Clears the FIFOs and asserts chip select
+    fn begin_transfer(&mut self) {
+        self.spi.CS.modify(CS::CLEAR::ClearTxRx + CS::TA::SET);
+    }
+
+    /// Converts into a DMA driven SPI
+    ///
+    /// `control_blocks` must be 256 bit aligned and hold at least 2
+    /// control blocks
+    pub fn with_dma(
+        mut self,
+        tx_channel: dma::Channel,
+        rx_channel: dma::Channel,
+        control_blocks: Buffer,
+    ) -> SpiDma<PINS> {
+        assert_eq!(
+            control_blocks.paddr() & 0x1F,
+            0,
+            "Control block address must be 256 bit aligned"
+        );
+        assert!(control_blocks.size() >= 2 * dma::CONTROL_BLOCK_SIZE);
+
+        self.end_transfer();
+
+        SpiDma {
+            spi: self,
+            tx_channel,
+            rx_channel,
+            control_blocks,
+        }
+    }
+
+    pub fn free(mut self) -> (SPI0, PINS) {
+        self.end_transfer();
+        (self.spi, self.pins)
+    }
+}
+
+impl<PINS> FullDuplex<u8> for Spi<SPI0, PINS> {
+    // No errors
+    type Error = Void;
+
+    fn read(&mut self) -> nb::Result<u8, Void> {
+        if self.spi.CS.is_set(CS::RXD) {
+            Ok(self.spi.FIFO.get() as u8)
+        } else {
+            Err(nb::Error::WouldBlock)
+        }
+    }
+
+    /// Starts a transfer if one is not already active, chip select stays
+    /// asserted until `end_transfer()` is called
+    fn send(&mut self, byte: u8) -> nb::Result<(), Void> {
+        if !self.spi.CS.is_set(CS::TA) {
+            self.begin_transfer();
+        }
+
+        if self.spi.CS.is_set(CS::TXD) {
+            self.spi.FIFO.set(byte as _);
+            Ok(())
+        } else {
+            Err(nb::Error::WouldBlock)
+        }
+    }
+}
+
+impl<PINS> blocking::Transfer<u8> for Spi<SPI0, PINS> {
+    type Error = Void;
+
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Void> {
+        self.end_transfer();
+        self.begin_transfer();
+
+        for word in words.iter_mut() {
+            block!(self.send(*word))?;
+            *word = block!(self.read())?;
+        }
+
+        self.end_transfer();
+
+        Ok(words)
+    }
+}
+
+impl<PINS> blocking::Write<u8> for Spi<SPI0, PINS> {
+    type Error = Void;
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Void> {
+        self.end_transfer();
+        self.begin_transfer();
+
+        // RX FIFO must be drained or the transfer stalls
+        for word in words {
+            block!(self.send(*word))?;
+            block!(self.read())?;
+        }
+
+        self.end_transfer();
+
+        Ok(())
+    }
+}
+
+/// Index of the TX control block in the control blocks pmem
+const TX_CONTROL_BLOCK: usize = 0;
+
+/// Index of the RX control block in the control blocks pmem
+const RX_CONTROL_BLOCK: usize = 1;
+
+/// Largest transfer, DLEN is 16 bits
+const MAX_DMA_TRANSFER_LEN: usize = 0xFFFF;
+
+/// SPI0 with TX and RX on two DMA channels paced by the SPI DREQs
+pub struct SpiDma<PINS> {
+    spi: Spi<SPI0, PINS>,
+    tx_channel: dma::Channel,
+    rx_channel: dma::Channel,
+    control_blocks: Buffer,
+}
+
+/// An in-flight DMA transfer, owns the SPI and the buffers until `wait()`
+pub struct SpiDmaTransfer<PINS> {
+    spi: SpiDma<PINS>,
+    tx: Buffer,
+    rx: Option<Buffer>,
+}
+
+/// A DMA transfer that couldn't be started, gives back the SPI and the
+/// buffers along with the reason
+pub type SpiDmaError<PINS> = (SpiDma<PINS>, Buffer, Option<Buffer>, Error);
+
+impl<PINS> SpiDma<PINS> {
+    /// Transfers `len` bytes from `tx`, received bytes are written into `rx`
+    pub fn transfer(
+        self,
+        tx: Buffer,
+        rx: Buffer,
+        len: usize,
+    ) -> Result<SpiDmaTransfer<PINS>, SpiDmaError<PINS>> {
+        let rx = Some(rx);
+
+        match check_lengths(&tx, &rx, len) {
+            Ok(()) => Ok(self.start(tx, rx, len)),
+            Err(e) => Err((self, tx, rx, e)),
+        }
+    }
+
+    /// Transfers `len` bytes from `tx`, received bytes are discarded
+    pub fn write(self, tx: Buffer, len: usize) -> Result<SpiDmaTransfer<PINS>, SpiDmaError<PINS>> {
+        match check_lengths(&tx, &None, len) {
+            Ok(()) => Ok(self.start(tx, None, len)),
+            Err(e) => Err((self, tx, None, e)),
+        }
+    }
+
+    pub fn free(self) -> (Spi<SPI0, PINS>, dma::Channel, dma::Channel, Buffer) {
+        (
+            self.spi,
+            self.tx_channel,
+            self.rx_channel,
+            self.control_blocks,
+        )
+    }
+
+    /// The lengths must already be checked by `check_lengths`
+    fn start(mut self, tx: Buffer, rx: Option<Buffer>, len: usize) -> SpiDmaTransfer<PINS> {
+        // Memory to the SPI FIFO, paced by the TX DREQ
+        let tx_config = dma::ControlBlockConfig {
+            transfer_length: dma::TransferLength::ModeLinear(len as _),
+            wait_for_resp: true,
+            dest_dreq: true,
+            src_inc: true,
+            peripheral_map: dma::dreq::SPI_TX,
+            ..Default::default()
+        };
+
+        // SPI FIFO to memory (or nowhere), paced by the RX DREQ
+        let rx_config = dma::ControlBlockConfig {
+            transfer_length: dma::TransferLength::ModeLinear(len as _),
+            wait_for_resp: true,
+            dest_inc: rx.is_some(),
+            dest_ignore: rx.is_none(),
+            src_dreq: true,
+            peripheral_map: dma::dreq::SPI_RX,
+            ..Default::default()
+        };
+
+        {
+            let control_blocks = self.control_blocks.as_mut_slice::<dma::ControlBlock>(2);
+
+            // NOTE: the FIFO bus address already has the L2 coherent alias
+            // bits set, so the translation in config() leaves it untouched
+            control_blocks[TX_CONTROL_BLOCK].config(&tx_config, tx.paddr(), FIFO_BUS_ADDR, 0, 0, 0);
+
+            control_blocks[RX_CONTROL_BLOCK].config(
+                &rx_config,
+                FIFO_BUS_ADDR,
+                rx.as_ref().map_or(0, |rx| rx.paddr()),
+                0,
+                0,
+                0,
+            );
+        }
+
+        while self.tx_channel.is_busy() || self.rx_channel.is_busy() {}
+
+        // Let the DMA DREQs drive the FIFO, the transfer length comes from DLEN
+        self.spi.spi.DLEN.write(DLEN::LEN.val(len as _));
+        self.spi
+            .spi
+            .CS
+            .modify(CS::CLEAR::ClearTxRx + CS::DMAEN::SET + CS::ADCS::SET + CS::TA::SET);
+
+        // RX is started first so it is ready for the first received bytes
+        let cb_paddr = self.control_blocks.paddr();
+        self.rx_channel
+            .start(cb_paddr + (RX_CONTROL_BLOCK * dma::CONTROL_BLOCK_SIZE) as u32);
+        self.tx_channel
+            .start(cb_paddr + (TX_CONTROL_BLOCK * dma::CONTROL_BLOCK_SIZE) as u32);
+
+        SpiDmaTransfer { spi: self, tx, rx }
+    }
+}
+
+fn check_lengths(tx: &Buffer, rx: &Option<Buffer>, len: usize) -> Result<(), Error> {
+    if (len == 0) || (len > MAX_DMA_TRANSFER_LEN) {
+        return Err(Error::TransferLength);
+    }
+
+    if (tx.size() < len) || rx.as_ref().map_or(false, |rx| rx.size() < len) {
+        return Err(Error::BufferTooSmall);
+    }
+
+    Ok(())
+}
+
+impl<PINS> SpiDmaTransfer<PINS> {
+    /// Returns true if both DMA channels are done
+    pub fn is_done(&self) -> bool {
+        !self.spi.tx_channel.is_busy() && !self.spi.rx_channel.is_busy()
+    }
+
+    /// Waits for the transfer to complete, returns the SPI and the buffers
+    /// along with the result of the transfer
+    pub fn wait(self) -> (SpiDma<PINS>, Buffer, Option<Buffer>, Result<(), Error>) {
+        let spi = self.spi;
+
+        // A TX error starves RX of its DREQs, so stop waiting as soon as
+        // either channel has stopped on an error
+        while (spi.tx_channel.is_busy() || spi.rx_channel.is_busy())
+            && !spi.tx_channel.has_error()
+            && !spi.rx_channel.has_error()
+        {}
+
+        let result = if spi.tx_channel.has_error() {
+            Err(Error::TxDma)
+        } else if spi.rx_channel.has_error() {
+            Err(Error::RxDma)
+        } else {
+            spi.tx_channel.wait();
+            spi.rx_channel.wait();
+
+            if spi.tx_channel.errors() {
+                Err(Error::TxDma)
+            } else if spi.rx_channel.errors() {
+                Err(Error::RxDma)
+            } else {
+                Ok(())
+            }
+        };
+
+        if result.is_ok() {
+            while !spi.spi.spi.CS.is_set(CS::DONE) {}
+        } else {
+            // Stops whichever channel is still waiting on DREQs
+            spi.tx_channel.reset();
+            spi.rx_channel.reset();
+        }
+
+        spi.spi
+            .spi
+            .CS
+            .modify(CS::TA::CLEAR + CS::DMAEN::CLEAR + CS::ADCS::CLEAR);
+
+        (spi, self.tx, self.rx, result)
+    }
+}
@@ -0,0 +1,144 @@
+//! Thermal monitoring
+//!
+//! Samples the SoC temperature through the mailbox at a fixed period
+
+use mailbox::{Channel, Error, Mailbox};
+use mailbox_msg::{
+    GetMaxTemperatureCmd, GetMaxTemperatureResp, GetTemperatureCmd, GetTemperatureResp, Tag,
+};
+use time::{Duration, Instant};
+use timer::MonoTimer;
+
+/// SoC temperature sensor id
+const SOC_TEMPERATURE_ID: u32 = 0;
+
+/// Number of samples averaged
+pub const HISTORY_LEN: usize = 16;
+
+/// Temperatures are in thousandths of a degree C
+pub struct ThermalMonitor {
+    timer: MonoTimer,
+    period: Duration,
+    next_sample: Instant,
+    max_temperature: u32,
+    history: [u32; HISTORY_LEN],
+    history_len: usize,
+    history_index: usize,
+    min: u32,
+    max: u32,
+}
+
+impl ThermalMonitor {
+    /// Queries the throttling temperature and takes the first sample
+    pub fn new(mbox: &mut Mailbox, timer: MonoTimer, period: Duration) -> Result<Self, Error> {
+        let max: GetMaxTemperatureResp = mbox
+            .call_batch(
+                Channel::Prop,
+                &GetMaxTemperatureCmd {
+                    id: SOC_TEMPERATURE_ID,
+                },
+            )?
+            .get()?;
+
+        if max.value == 0 {
+            return Err(Error::InvalidResponse(Tag::GetMaxTemperature));
+        }
+
+        let mut monitor = ThermalMonitor {
+            timer,
+            period,
+            next_sample: timer.now(),
+            max_temperature: max.value,
+            history: [0; HISTORY_LEN],
+            history_len: 0,
+            history_index: 0,
+            min: u32::max_value(),
+            max: 0,
+        };
+
+        monitor.sample(mbox)?;
+
+        Ok(monitor)
+    }
+
+    /// Takes a sample if the period has elapsed, returns the new temperature
+    pub fn poll(&mut self, mbox: &mut Mailbox) -> Result<Option<u32>, Error> {
+        if self.timer.now() < self.next_sample {
+            return Ok(None);
+        }
+
+        self.sample(mbox).map(Some)
+    }
+
+    /// Takes a sample now, restarting the period
+    pub fn sample(&mut self, mbox: &mut Mailbox) -> Result<u32, Error> {
+        let resp: GetTemperatureResp = mbox
+            .call_batch(
+                Channel::Prop,
+                &GetTemperatureCmd {
+                    id: SOC_TEMPERATURE_ID,
+                },
+            )?
+            .get()?;
+
+        self.next_sample = self.timer.now() + self.period;
+        self.record(resp.value);
+
+        Ok(resp.value)
+    }
+
+    /// Most recent sample
+    pub fn current(&self) -> u32 {
+        let last = (self.history_index + HISTORY_LEN - 1) % HISTORY_LEN;
+        self.history[last]
+    }
+
+    /// Lowest sample since creation
+    pub fn min(&self) -> u32 {
+        self.min
+    }
+
+    /// Highest sample since creation
+    pub fn max(&self) -> u32 {
+        self.max
+    }
+
+    /// Average of the last `HISTORY_LEN` samples
+    pub fn average(&self) -> u32 {
+        let sum: u64 = self.history[..self.history_len]
+            .iter()
+            .map(|&t| u64::from(t))
+            .sum();
+        (sum / self.history_len as u64) as u32
+    }
+
+    /// Temperature at which the firmware starts throttling
+    pub fn max_temperature(&self) -> u32 {
+        self.max_temperature
+    }
+
+    /// Current temperature as a fraction of `max_temperature`, 0.0 to 1.0
+    pub fn load(&self) -> f32 {
+        let load = self.current() as f32 / self.max_temperature as f32;
+        if load >= 1.0 {
+            1.0
+        } else {
+            load
+        }
+    }
+
+    fn record(&mut self, temperature: u32) {
+        self.history[self.history_index] = temperature;
+        self.history_index = (self.history_index + 1) % HISTORY_LEN;
+        if self.history_len < HISTORY_LEN {
+            self.history_len += 1;
+        }
+
+        if temperature < self.min {
+            self.min = temperature;
+        }
+        if temperature > self.max {
+            self.max = temperature;
+        }
+    }
+}
@@ -1,5 +1,9 @@
 //! Time units
 
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+use timer::MonoTimer;
+
 /// Bits per second
 #[derive(Clone, Copy, Debug)]
 pub struct Bps(pub u32);
@@ -20,6 +24,10 @@ pub struct MegaHertz(pub u32);
 #[derive(Clone, Copy, Debug)]
 pub struct MilliSeconds(pub u32);
 
+/// MicroSeconds
+#[derive(Clone, Copy, Debug)]
+pub struct MicroSeconds(pub u32);
+
 /// Extension trait that adds convenience methods to the `u32` type
 pub trait U32Ext {
     /// Wrap in `Bps`
@@ -36,6 +44,9 @@ pub trait U32Ext {
 
     /// Wrap in `MilliSeconds`
     fn ms(self) -> MilliSeconds;
+
+    /// Wrap in `MicroSeconds`
+    fn us(self) -> MicroSeconds;
 }
 
 impl U32Ext for u32 {
@@ -58,6 +69,10 @@ impl U32Ext for u32 {
     fn ms(self) -> MilliSeconds {
         MilliSeconds(self)
     }
+
+    fn us(self) -> MicroSeconds {
+        MicroSeconds(self)
+    }
 }
 
 impl Into<Hertz> for KiloHertz {
@@ -77,3 +92,193 @@ impl Into<KiloHertz> for MegaHertz {
         KiloHertz(self.0 * 1_000)
     }
 }
+
+/// Span of time, with microsecond resolution
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Duration {
+    us: u64,
+}
+
+impl Duration {
+    pub fn from_micros(us: u64) -> Self {
+        Duration { us }
+    }
+
+    pub fn from_millis(ms: u64) -> Self {
+        Duration { us: ms * 1_000 }
+    }
+
+    pub fn from_secs(secs: u64) -> Self {
+        Duration {
+            us: secs * 1_000_000,
+        }
+    }
+
+    pub fn as_micros(&self) -> u64 {
+        self.us
+    }
+
+    pub fn as_millis(&self) -> u64 {
+        self.us / 1_000
+    }
+
+    pub fn as_secs(&self) -> u64 {
+        self.us / 1_000_000
+    }
+
+    /// Returns `None` on overflow
+    pub fn checked_add(self, rhs: Duration) -> Option<Duration> {
+        self.us.checked_add(rhs.us).map(Duration::from_micros)
+    }
+
+    /// Returns `None` if `rhs` is longer than `self`
+    pub fn checked_sub(self, rhs: Duration) -> Option<Duration> {
+        self.us.checked_sub(rhs.us).map(Duration::from_micros)
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+
+    fn add(self, rhs: Duration) -> Duration {
+        self.checked_add(rhs)
+            .expect("overflow when adding durations")
+    }
+}
+
+impl AddAssign for Duration {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Duration {
+    type Output = Duration;
+
+    fn sub(self, rhs: Duration) -> Duration {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting durations")
+    }
+}
+
+impl SubAssign for Duration {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+impl From<MilliSeconds> for Duration {
+    fn from(ms: MilliSeconds) -> Duration {
+        Duration::from_millis(ms.0 as u64)
+    }
+}
+
+impl From<MicroSeconds> for Duration {
+    fn from(us: MicroSeconds) -> Duration {
+        Duration::from_micros(us.0 as u64)
+    }
+}
+
+/// Truncates to whole milliseconds, saturates at `u32::MAX`
+impl Into<MilliSeconds> for Duration {
+    fn into(self) -> MilliSeconds {
+        MilliSeconds(min_u32(self.as_millis()))
+    }
+}
+
+/// Saturates at `u32::MAX`
+impl Into<MicroSeconds> for Duration {
+    fn into(self) -> MicroSeconds {
+        MicroSeconds(min_u32(self.as_micros()))
+    }
+}
+
+fn min_u32(val: u64) -> u32 {
+    if val > ::core::u32::MAX as u64 {
+        ::core::u32::MAX
+    } else {
+        val as u32
+    }
+}
+
+/// Point in time of the monotonic system timer counter, which starts
+/// at zero on reset and never wraps in practice
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Instant {
+    us: u64,
+}
+
+impl Instant {
+    /// Instant from a system timer counter value
+    pub fn from_micros(us: u64) -> Self {
+        Instant { us }
+    }
+
+    /// System timer counter value
+    pub fn as_micros(&self) -> u64 {
+        self.us
+    }
+
+    /// Time elapsed since this instant
+    pub fn elapsed(&self, timer: &MonoTimer) -> Duration {
+        timer.now().duration_since(*self)
+    }
+
+    /// Returns `None` if `earlier` is later than `self`
+    pub fn checked_duration_since(&self, earlier: Instant) -> Option<Duration> {
+        self.us.checked_sub(earlier.us).map(Duration::from_micros)
+    }
+
+    /// Saturates to zero if `earlier` is later than `self`
+    pub fn duration_since(&self, earlier: Instant) -> Duration {
+        self.checked_duration_since(earlier).unwrap_or_default()
+    }
+
+    /// Returns `None` on overflow
+    pub fn checked_add(&self, duration: Duration) -> Option<Instant> {
+        self.us.checked_add(duration.us).map(Instant::from_micros)
+    }
+
+    /// Returns `None` on underflow
+    pub fn checked_sub(&self, duration: Duration) -> Option<Instant> {
+        self.us.checked_sub(duration.us).map(Instant::from_micros)
+    }
+}
+
+impl Add<Duration> for Instant {
+    type Output = Instant;
+
+    fn add(self, rhs: Duration) -> Instant {
+        self.checked_add(rhs)
+            .expect("overflow when adding duration to instant")
+    }
+}
+
+impl AddAssign<Duration> for Instant {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Duration> for Instant {
+    type Output = Instant;
+
+    fn sub(self, rhs: Duration) -> Instant {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting duration from instant")
+    }
+}
+
+impl SubAssign<Duration> for Instant {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub<Instant> for Instant {
+    type Output = Duration;
+
+    fn sub(self, rhs: Instant) -> Duration {
+        self.duration_since(rhs)
+    }
+}
@@ -0,0 +1,185 @@
+//! Timers
+//!
+//! Uses the 1 MHz system timer, only compare channels 1 and 3 are
+//! available to the ARM
+
+use bcm2837::sys_timer::*;
+use hal::timer::{CountDown, Periodic};
+use void::Void;
+
+use time::{Hertz, Instant};
+
+/// System timer counter frequency
+pub const TIMER_FREQUENCY: Hertz = Hertz(1_000_000);
+
+/// Reads the 64 bit free-running counter, one tick per microsecond
+pub fn counter(timer: &SYSTIMER) -> u64 {
+    // The two halves can't be read atomically, retry if the upper half
+    // changed in between
+    loop {
+        let hi = timer.CHI.get();
+        let lo = timer.CLO.get();
+
+        if hi == timer.CHI.get() {
+            return ((hi as u64) << 32) | lo as u64;
+        }
+    }
+}
+
+/// Monotonic timer, reads the free-running counter without using
+/// any of the compare channels
+#[derive(Clone, Copy, Debug)]
+pub struct MonoTimer {
+    timer: SYSTIMER,
+}
+
+impl MonoTimer {
+    pub fn new(timer: SYSTIMER) -> Self {
+        MonoTimer { timer }
+    }
+
+    /// Returns the frequency at which the monotonic timer is operating at
+    pub fn frequency(&self) -> Hertz {
+        TIMER_FREQUENCY
+    }
+
+    /// Returns an `Instant` corresponding to "now"
+    pub fn now(&self) -> Instant {
+        Instant::from_micros(counter(&self.timer))
+    }
+
+    pub fn free(self) -> SYSTIMER {
+        self.timer
+    }
+}
+
+pub trait SysTimerExt {
+    type Parts;
+
+    fn split(self) -> Self::Parts;
+}
+
+/// The compare channels available to the ARM, each can only be used by
+/// one `Timer` at a time
+#[derive(Debug)]
+pub struct Parts {
+    pub ch1: Channel1,
+    pub ch3: Channel3,
+    pub mono: MonoTimer,
+}
+
+/// Compare channel 1
+#[derive(Debug)]
+pub struct Channel1 {
+    timer: SYSTIMER,
+}
+
+/// Compare channel 3
+#[derive(Debug)]
+pub struct Channel3 {
+    timer: SYSTIMER,
+}
+
+impl SysTimerExt for SYSTIMER {
+    type Parts = Parts;
+
+    fn split(self) -> Parts {
+        Parts {
+            ch1: Channel1 { timer: self },
+            ch3: Channel3 { timer: self },
+            mono: MonoTimer::new(self),
+        }
+    }
+}
+
+/// Hardware timer on one of the system timer compare channels
+pub struct Timer<CH> {
+    channel: CH,
+    ticks: u32,
+}
+
+macro_rules! timers {
+    ($($CHANNEL:ident: ($channel:ident, $C:ident, $M:ident),)+) => {
+        $(
+            impl Timer<$CHANNEL> {
+                /// Configures the compare channel as a periodic count down
+                /// timer
+                pub fn $channel<T>(channel: $CHANNEL, timeout: T) -> Self
+                where
+                    T: Into<Hertz>,
+                {
+                    let mut timer = Timer { channel, ticks: 0 };
+                    timer.start(timeout);
+
+                    timer
+                }
+
+                /// Returns true if the compare value has been reached,
+                /// the system timer interrupt for the channel is pending
+                /// until `clear_match` is called
+                pub fn is_match(&self) -> bool {
+                    self.channel.timer.CS.is_set(CS::$M)
+                }
+
+                /// Clears the match flag, and the pending interrupt
+                pub fn clear_match(&mut self) {
+                    // Writing zero to the other match bits has no effect
+                    self.channel.timer.CS.write(CS::$M::SET);
+                }
+
+                pub fn free(self) -> $CHANNEL {
+                    self.channel
+                }
+            }
+
+            impl CountDown for Timer<$CHANNEL> {
+                type Time = Hertz;
+
+                fn start<T>(&mut self, timeout: T)
+                where
+                    T: Into<Hertz>,
+                {
+                    let freq = timeout.into().0;
+                    assert_ne!(freq, 0);
+
+                    self.ticks = TIMER_FREQUENCY.0 / freq;
+                    assert_ne!(self.ticks, 0, "Timer frequency out of range");
+
+                    self.clear_match();
+                    let now = self.channel.timer.CLO.get();
+                    self.channel.timer.$C.set(now.wrapping_add(self.ticks));
+                }
+
+                fn wait(&mut self) -> nb::Result<(), Void> {
+                    if !self.is_match() {
+                        return Err(nb::Error::WouldBlock);
+                    }
+
+                    self.clear_match();
+
+                    // Advance from the previous compare value so the
+                    // period doesn't drift
+                    let mut next = self.channel.timer.$C.get().wrapping_add(self.ticks);
+
+                    // Polled more than a period late, the compare value is
+                    // already behind and would only match after CLO wraps
+                    let now = self.channel.timer.CLO.get();
+                    if (next.wrapping_sub(now) as i32) <= 0 {
+                        next = now.wrapping_add(self.ticks);
+                    }
+
+                    self.channel.timer.$C.set(next);
+
+                    Ok(())
+                }
+            }
+
+            impl Periodic for Timer<$CHANNEL> {}
+        )+
+    }
+}
+
+timers! {
+    Channel1: (channel1, C1, M1),
+    Channel3: (channel3, C3, M3),
+}
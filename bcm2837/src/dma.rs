@@ -127,6 +127,9 @@ pub const CHANNEL12_OFFSET: u64 = 0xC00;
 pub const CHANNEL13_OFFSET: u64 = 0xD00;
 pub const CHANNEL14_OFFSET: u64 = 0xE00;
 
+/// Channel 15 is not in the same block as the others
+pub const CHANNEL15_PADDR: u64 = MMIO_BASE + 0x00E0_5000;
+
 /// Offset of the global interrupt status register
 pub const INT_STATUS_OFFSET: u64 = 0xFE0;
 
@@ -187,3 +190,33 @@ impl Deref for DMA {
         unsafe { &*self.as_ptr() }
     }
 }
+
+/// DMA channel 15, in its own register block at `CHANNEL15_PADDR`
+#[derive(Debug, Copy, Clone)]
+pub struct DMA15 {
+    addr: *const u64,
+}
+
+impl From<u64> for DMA15 {
+    fn from(vaddr: u64) -> DMA15 {
+        assert_ne!(vaddr, 0);
+        DMA15 {
+            addr: vaddr as *const u64,
+        }
+    }
+}
+
+unsafe impl Send for DMA15 {}
+
+impl DMA15 {
+    pub fn as_ptr(&self) -> *const RegisterBlock {
+        self.addr as *const _
+    }
+}
+
+impl Deref for DMA15 {
+    type Target = RegisterBlock;
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.as_ptr() }
+    }
+}
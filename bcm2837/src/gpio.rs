@@ -66,40 +66,592 @@ register_bitfields! {
             AF3 = 0b111,
             AF4 = 0b011,
             AF5 = 0b010
+        ],
+
+        /// Pin 4
+        FSEL4 OFFSET(12) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100, // GPCLK0 - Alternate function 0
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 3
+        FSEL3 OFFSET(9) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100, // I2C1 SCL - Alternate function 0
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 2
+        FSEL2 OFFSET(6) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100, // I2C1 SDA - Alternate function 0
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 1
+        FSEL1 OFFSET(3) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100, // I2C0 SCL - Alternate function 0
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 0
+        FSEL0 OFFSET(0) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100, // I2C0 SDA - Alternate function 0
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ]
+    ],
+
+    /// GPIO Function Select 1
+    GPFSEL1 [
+        /// Pin 19
+        FSEL19 OFFSET(27) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010 // PWM1 - Alternate function 5
+        ],
+
+        /// Pin 18
+        FSEL18 OFFSET(24) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010 // PWM0 - Alternate function 5
+        ],
+
+        /// Pin 17
+        FSEL17 OFFSET(21) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111, // UART0 RTS - Alternate function 3
+            AF4 = 0b011,
+            AF5 = 0b010 // Mini UART RTS - Alternate function 5
+        ],
+
+        /// Pin 16
+        FSEL16 OFFSET(18) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111, // UART0 CTS - Alternate function 3
+            AF4 = 0b011,
+            AF5 = 0b010 // Mini UART CTS - Alternate function 5
+        ],
+
+        /// Pin 15
+        FSEL15 OFFSET(15) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100, // UART0 - Alternate function 0
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010 // Mini UART - Alternate function 5
+        ],
+
+        /// Pin 14
+        FSEL14 OFFSET(12) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100, // UART0 - Alternate function 0
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010 // Mini UART - Alternate function 5
+        ],
+
+        /// Pin 13
+        FSEL13 OFFSET(9) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100, // PWM1 - Alternate function 0
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 12
+        FSEL12 OFFSET(6) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100, // PWM0 - Alternate function 0
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 11
+        FSEL11 OFFSET(3) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100, // SPI0 clock - Alternate function 0
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 10
+        FSEL10 OFFSET(0) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100, // SPI0 MOSI - Alternate function 0
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ]
+    ],
+
+    /// GPIO Function Select 2
+    GPFSEL2 [
+        /// Pin 29
+        FSEL29 OFFSET(27) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 28
+        FSEL28 OFFSET(24) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 27
+        FSEL27 OFFSET(21) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 26
+        FSEL26 OFFSET(18) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 25
+        FSEL25 OFFSET(15) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 24
+        FSEL24 OFFSET(12) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 23
+        FSEL23 OFFSET(9) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 22
+        FSEL22 OFFSET(6) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 21
+        FSEL21 OFFSET(3) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 20
+        FSEL20 OFFSET(0) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ]
+    ],
+
+    /// GPIO Function Select 3
+    GPFSEL3 [
+        /// Pin 39
+        FSEL39 OFFSET(27) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 38
+        FSEL38 OFFSET(24) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 37
+        FSEL37 OFFSET(21) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 36
+        FSEL36 OFFSET(18) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 35
+        FSEL35 OFFSET(15) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 34
+        FSEL34 OFFSET(12) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 33
+        FSEL33 OFFSET(9) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 32
+        FSEL32 OFFSET(6) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 31
+        FSEL31 OFFSET(3) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 30
+        FSEL30 OFFSET(0) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ]
+    ],
+
+    /// GPIO Function Select 4
+    GPFSEL4 [
+        /// Pin 49
+        FSEL49 OFFSET(27) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 48
+        FSEL48 OFFSET(24) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 47
+        FSEL47 OFFSET(21) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 46
+        FSEL46 OFFSET(18) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 45
+        FSEL45 OFFSET(15) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100, // PWM1 - Alternate function 0
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 44
+        FSEL44 OFFSET(12) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 43
+        FSEL43 OFFSET(9) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 42
+        FSEL42 OFFSET(6) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100,
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 41
+        FSEL41 OFFSET(3) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100, // PWM1 - Alternate function 0
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
+        ],
+
+        /// Pin 40
+        FSEL40 OFFSET(0) NUMBITS(3) [
+            Input = 0b000,
+            Output = 0b001,
+            AF0 = 0b100, // PWM0 - Alternate function 0
+            AF1 = 0b101,
+            AF2 = 0b110,
+            AF3 = 0b111,
+            AF4 = 0b011,
+            AF5 = 0b010
         ]
     ],
 
-    /// GPIO Function Select 1
-    GPFSEL1 [
-        /// Pin 15
-        FSEL15 OFFSET(15) NUMBITS(3) [
+    /// GPIO Function Select 5
+    GPFSEL5 [
+        /// Pin 53
+        FSEL53 OFFSET(9) NUMBITS(3) [
             Input = 0b000,
             Output = 0b001,
-            AF0 = 0b100, // UART0 - Alternate function 0
+            AF0 = 0b100,
             AF1 = 0b101,
             AF2 = 0b110,
             AF3 = 0b111,
             AF4 = 0b011,
-            AF5 = 0b010 // Mini UART - Alternate function 5
+            AF5 = 0b010
         ],
 
-        /// Pin 14
-        FSEL14 OFFSET(12) NUMBITS(3) [
+        /// Pin 52
+        FSEL52 OFFSET(6) NUMBITS(3) [
             Input = 0b000,
             Output = 0b001,
-            AF0 = 0b100, // UART0 - Alternate function 0
+            AF0 = 0b100,
             AF1 = 0b101,
             AF2 = 0b110,
             AF3 = 0b111,
             AF4 = 0b011,
-            AF5 = 0b010 // Mini UART - Alternate function 5
+            AF5 = 0b010
         ],
 
-        /// Pin 11
-        FSEL11 OFFSET(3) NUMBITS(3) [
+        /// Pin 51
+        FSEL51 OFFSET(3) NUMBITS(3) [
             Input = 0b000,
             Output = 0b001,
-            AF0 = 0b100, // SPI0 clock - Alternate function 0
+            AF0 = 0b100,
             AF1 = 0b101,
             AF2 = 0b110,
             AF3 = 0b111,
@@ -107,11 +659,11 @@ register_bitfields! {
             AF5 = 0b010
         ],
 
-        /// Pin 10
-        FSEL10 OFFSET(0) NUMBITS(3) [
+        /// Pin 50
+        FSEL50 OFFSET(0) NUMBITS(3) [
             Input = 0b000,
             Output = 0b001,
-            AF0 = 0b100, // SPI0 MOSI - Alternate function 0
+            AF0 = 0b100,
             AF1 = 0b101,
             AF2 = 0b110,
             AF3 = 0b111,
@@ -132,6 +684,102 @@ register_bitfields! {
 
     /// GPIO Pull-up/down Clock Register 0
     GPPUDCLK0 [
+        /// Pin 31
+        PUDCLK31 OFFSET(31) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 30
+        PUDCLK30 OFFSET(30) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 29
+        PUDCLK29 OFFSET(29) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 28
+        PUDCLK28 OFFSET(28) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 27
+        PUDCLK27 OFFSET(27) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 26
+        PUDCLK26 OFFSET(26) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 25
+        PUDCLK25 OFFSET(25) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 24
+        PUDCLK24 OFFSET(24) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 23
+        PUDCLK23 OFFSET(23) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 22
+        PUDCLK22 OFFSET(22) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 21
+        PUDCLK21 OFFSET(21) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 20
+        PUDCLK20 OFFSET(20) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 19
+        PUDCLK19 OFFSET(19) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 18
+        PUDCLK18 OFFSET(18) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 17
+        PUDCLK17 OFFSET(17) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 16
+        PUDCLK16 OFFSET(16) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
         /// Pin 15
         PUDCLK15 OFFSET(15) NUMBITS(1) [
             NoEffect = 0,
@@ -196,6 +844,171 @@ register_bitfields! {
         PUDCLK5 OFFSET(5) NUMBITS(1) [
             NoEffect = 0,
             AssertClock = 1
+        ],
+
+        /// Pin 4
+        PUDCLK4 OFFSET(4) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 3
+        PUDCLK3 OFFSET(3) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 2
+        PUDCLK2 OFFSET(2) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 1
+        PUDCLK1 OFFSET(1) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 0
+        PUDCLK0 OFFSET(0) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ]
+    ],
+
+    /// GPIO Pull-up/down Clock Register 1
+    GPPUDCLK1 [
+        /// Pin 53
+        PUDCLK53 OFFSET(21) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 52
+        PUDCLK52 OFFSET(20) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 51
+        PUDCLK51 OFFSET(19) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 50
+        PUDCLK50 OFFSET(18) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 49
+        PUDCLK49 OFFSET(17) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 48
+        PUDCLK48 OFFSET(16) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 47
+        PUDCLK47 OFFSET(15) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 46
+        PUDCLK46 OFFSET(14) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 45
+        PUDCLK45 OFFSET(13) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 44
+        PUDCLK44 OFFSET(12) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 43
+        PUDCLK43 OFFSET(11) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 42
+        PUDCLK42 OFFSET(10) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 41
+        PUDCLK41 OFFSET(9) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 40
+        PUDCLK40 OFFSET(8) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 39
+        PUDCLK39 OFFSET(7) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 38
+        PUDCLK38 OFFSET(6) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 37
+        PUDCLK37 OFFSET(5) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 36
+        PUDCLK36 OFFSET(4) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 35
+        PUDCLK35 OFFSET(3) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 34
+        PUDCLK34 OFFSET(2) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 33
+        PUDCLK33 OFFSET(1) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
+        ],
+
+        /// Pin 32
+        PUDCLK32 OFFSET(0) NUMBITS(1) [
+            NoEffect = 0,
+            AssertClock = 1
         ]
     ]
 }
@@ -205,20 +1018,46 @@ pub const PADDR: u64 = MMIO_BASE + 0x0020_0000;
 #[allow(non_snake_case)]
 #[repr(C)]
 pub struct RegisterBlock {
-    pub GPFSEL0: ReadWrite<u32, GPFSEL0::Register>, // 0x00
-    pub GPFSEL1: ReadWrite<u32, GPFSEL1::Register>, // 0x04
-    __reserved_0: [u32; 5],                         // 0x08
-    pub GPSET0: ReadWrite<u32>,                     // 0x1C
-    pub GPSET1: ReadWrite<u32>,                     // 0x20
-    __reserved_1: u32,                              // 0x24
-    pub GPCLR0: ReadWrite<u32>,                     // 0x28
-    pub GPCLR1: ReadWrite<u32>,                     // 0x2C
-    __reserved_2: u32,                              // 0x30
-    pub GPLEV0: ReadWrite<u32>,                     // 0x34
-    pub GPLEV1: ReadWrite<u32>,                     // 0x38
-    __reserved_3: [u32; 22],                        // 0x3C
-    pub GPPUD: ReadWrite<u32, GPPUD::Register>,     // 0x94
-    pub GPPUDCLK0: ReadWrite<u32, GPPUDCLK0::Register>, //0x98
+    pub GPFSEL0: ReadWrite<u32, GPFSEL0::Register>,     // 0x00
+    pub GPFSEL1: ReadWrite<u32, GPFSEL1::Register>,     // 0x04
+    pub GPFSEL2: ReadWrite<u32, GPFSEL2::Register>,     // 0x08
+    pub GPFSEL3: ReadWrite<u32, GPFSEL3::Register>,     // 0x0C
+    pub GPFSEL4: ReadWrite<u32, GPFSEL4::Register>,     // 0x10
+    pub GPFSEL5: ReadWrite<u32, GPFSEL5::Register>,     // 0x14
+    __reserved_0: u32,                                  // 0x18
+    pub GPSET0: ReadWrite<u32>,                         // 0x1C
+    pub GPSET1: ReadWrite<u32>,                         // 0x20
+    __reserved_1: u32,                                  // 0x24
+    pub GPCLR0: ReadWrite<u32>,                         // 0x28
+    pub GPCLR1: ReadWrite<u32>,                         // 0x2C
+    __reserved_2: u32,                                  // 0x30
+    pub GPLEV0: ReadWrite<u32>,                         // 0x34
+    pub GPLEV1: ReadWrite<u32>,                         // 0x38
+    __reserved_3: u32,                                  // 0x3C
+    pub GPEDS0: ReadWrite<u32>,                         // 0x40
+    pub GPEDS1: ReadWrite<u32>,                         // 0x44
+    __reserved_4: u32,                                  // 0x48
+    pub GPREN0: ReadWrite<u32>,                         // 0x4C
+    pub GPREN1: ReadWrite<u32>,                         // 0x50
+    __reserved_5: u32,                                  // 0x54
+    pub GPFEN0: ReadWrite<u32>,                         // 0x58
+    pub GPFEN1: ReadWrite<u32>,                         // 0x5C
+    __reserved_6: u32,                                  // 0x60
+    pub GPHEN0: ReadWrite<u32>,                         // 0x64
+    pub GPHEN1: ReadWrite<u32>,                         // 0x68
+    __reserved_7: u32,                                  // 0x6C
+    pub GPLEN0: ReadWrite<u32>,                         // 0x70
+    pub GPLEN1: ReadWrite<u32>,                         // 0x74
+    __reserved_8: u32,                                  // 0x78
+    pub GPAREN0: ReadWrite<u32>,                        // 0x7C
+    pub GPAREN1: ReadWrite<u32>,                        // 0x80
+    __reserved_9: u32,                                  // 0x84
+    pub GPAFEN0: ReadWrite<u32>,                        // 0x88
+    pub GPAFEN1: ReadWrite<u32>,                        // 0x8C
+    __reserved_10: u32,                                 // 0x90
+    pub GPPUD: ReadWrite<u32, GPPUD::Register>,         // 0x94
+    pub GPPUDCLK0: ReadWrite<u32, GPPUDCLK0::Register>, // 0x98
+    pub GPPUDCLK1: ReadWrite<u32, GPPUDCLK1::Register>, // 0x9C
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -5,8 +5,13 @@ extern crate register;
 
 const MMIO_BASE: u64 = 0x3F00_0000;
 
+/// Peripheral base address as seen by the DMA engine
+const MMIO_BUS_BASE: u32 = 0x7E00_0000;
+
 pub mod dma;
 pub mod gpio;
 pub mod mbox;
 pub mod spi0;
+pub mod sys_timer;
+pub mod uart0;
 pub mod uart1;
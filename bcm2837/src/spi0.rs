@@ -1,6 +1,6 @@
 //! SPI0
 
-use super::MMIO_BASE;
+use super::{MMIO_BASE, MMIO_BUS_BASE};
 
 use core::ops::Deref;
 use register::mmio::ReadWrite;
@@ -121,6 +121,9 @@ register_bitfields! {
 
 pub const PADDR: u64 = MMIO_BASE + 0x20_4000;
 
+/// Bus address of the FIFO register, used as the DMA source/destination
+pub const FIFO_BUS_ADDR: u32 = MMIO_BUS_BASE + 0x20_4000 + 0x04;
+
 #[allow(non_snake_case)]
 #[repr(C)]
 pub struct RegisterBlock {
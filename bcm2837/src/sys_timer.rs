@@ -0,0 +1,70 @@
+//! System Timer
+//!
+//! Free-running 64 bit counter, incremented at 1 MHz, with four 32 bit
+//! compare channels. Channels 0 and 2 are used by the VideoCore GPU,
+//! channels 1 and 3 are free for the ARM
+
+use super::MMIO_BASE;
+
+use core::ops::Deref;
+use register::mmio::{ReadOnly, ReadWrite};
+
+register_bitfields! {
+    u32,
+
+    /// Control/Status
+    /// Writing a one to a bit clears the corresponding match
+    CS [
+        /// System Timer Match 3
+        M3 OFFSET(3) NUMBITS(1) [],
+        /// System Timer Match 2
+        M2 OFFSET(2) NUMBITS(1) [],
+        /// System Timer Match 1
+        M1 OFFSET(1) NUMBITS(1) [],
+        /// System Timer Match 0
+        M0 OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub const PADDR: u64 = MMIO_BASE + 0x3000;
+
+#[allow(non_snake_case)]
+#[repr(C)]
+pub struct RegisterBlock {
+    pub CS: ReadWrite<u32, CS::Register>, // 0x00
+    pub CLO: ReadOnly<u32>,               // 0x04 - Counter Lower 32 bits
+    pub CHI: ReadOnly<u32>,               // 0x08 - Counter Higher 32 bits
+    pub C0: ReadWrite<u32>,               // 0x0C - Compare 0
+    pub C1: ReadWrite<u32>,               // 0x10 - Compare 1
+    pub C2: ReadWrite<u32>,               // 0x14 - Compare 2
+    pub C3: ReadWrite<u32>,               // 0x18 - Compare 3
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SYSTIMER {
+    addr: *const u64,
+}
+
+impl From<u64> for SYSTIMER {
+    fn from(vaddr: u64) -> SYSTIMER {
+        assert_ne!(vaddr, 0);
+        SYSTIMER {
+            addr: vaddr as *const u64,
+        }
+    }
+}
+
+unsafe impl Send for SYSTIMER {}
+
+impl SYSTIMER {
+    pub fn as_ptr(&self) -> *const RegisterBlock {
+        self.addr as *const _
+    }
+}
+
+impl Deref for SYSTIMER {
+    type Target = RegisterBlock;
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.as_ptr() }
+    }
+}
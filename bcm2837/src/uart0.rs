@@ -0,0 +1,204 @@
+//! PL011 UART0
+
+use super::MMIO_BASE;
+
+use core::ops::Deref;
+use register::mmio::{ReadOnly, ReadWrite, WriteOnly};
+
+register_bitfields! {
+    u32,
+
+    /// Data Register
+    DR [
+        /// Overrun error
+        OE OFFSET(11) NUMBITS(1) [],
+        /// Break error
+        BE OFFSET(10) NUMBITS(1) [],
+        /// Parity error
+        PE OFFSET(9) NUMBITS(1) [],
+        /// Framing error
+        FE OFFSET(8) NUMBITS(1) [],
+        /// Data character
+        DATA OFFSET(0) NUMBITS(8) []
+    ],
+
+    /// Receive Status Register/Error Clear Register
+    RSRECR [
+        /// Overrun error
+        OE OFFSET(3) NUMBITS(1) [],
+        /// Break error
+        BE OFFSET(2) NUMBITS(1) [],
+        /// Parity error
+        PE OFFSET(1) NUMBITS(1) [],
+        /// Framing error
+        FE OFFSET(0) NUMBITS(1) []
+    ],
+
+    /// Flag Register
+    FR [
+        /// Transmit FIFO empty
+        TXFE OFFSET(7) NUMBITS(1) [],
+        /// Receive FIFO full
+        RXFF OFFSET(6) NUMBITS(1) [],
+        /// Transmit FIFO full
+        TXFF OFFSET(5) NUMBITS(1) [],
+        /// Receive FIFO empty
+        RXFE OFFSET(4) NUMBITS(1) [],
+        /// UART busy transmitting data
+        BUSY OFFSET(3) NUMBITS(1) [],
+        /// Clear to send, inverse of the nUARTCTS input
+        CTS OFFSET(0) NUMBITS(1) []
+    ],
+
+    /// Integer Baud Rate Divisor
+    IBRD [
+        IBRD OFFSET(0) NUMBITS(16) []
+    ],
+
+    /// Fractional Baud Rate Divisor
+    FBRD [
+        FBRD OFFSET(0) NUMBITS(6) []
+    ],
+
+    /// Line Control Register
+    LCRH [
+        /// Stick parity select
+        SPS OFFSET(7) NUMBITS(1) [],
+        /// Word length
+        WLEN OFFSET(5) NUMBITS(2) [
+            FiveBit = 0b00,
+            SixBit = 0b01,
+            SevenBit = 0b10,
+            EightBit = 0b11
+        ],
+        /// Enable FIFOs
+        FEN OFFSET(4) NUMBITS(1) [],
+        /// Two stop bits select
+        STP2 OFFSET(3) NUMBITS(1) [],
+        /// Even parity select
+        EPS OFFSET(2) NUMBITS(1) [],
+        /// Parity enable
+        PEN OFFSET(1) NUMBITS(1) [],
+        /// Send break
+        BRK OFFSET(0) NUMBITS(1) []
+    ],
+
+    /// Control Register
+    CR [
+        /// CTS hardware flow control enable
+        CTSEN OFFSET(15) NUMBITS(1) [],
+        /// RTS hardware flow control enable
+        RTSEN OFFSET(14) NUMBITS(1) [],
+        /// Request to send
+        RTS OFFSET(11) NUMBITS(1) [],
+        /// Receive enable
+        RXE OFFSET(9) NUMBITS(1) [],
+        /// Transmit enable
+        TXE OFFSET(8) NUMBITS(1) [],
+        /// Loopback enable
+        LBE OFFSET(7) NUMBITS(1) [],
+        /// UART enable
+        UARTEN OFFSET(0) NUMBITS(1) []
+    ],
+
+    /// Interrupt FIFO Level Select Register
+    IFLS [
+        /// Receive interrupt FIFO level select
+        RXIFLSEL OFFSET(3) NUMBITS(3) [
+            OneEighth = 0b000,
+            OneQuarter = 0b001,
+            OneHalf = 0b010,
+            ThreeQuarters = 0b011,
+            SevenEighths = 0b100
+        ],
+        /// Transmit interrupt FIFO level select
+        TXIFLSEL OFFSET(0) NUMBITS(3) [
+            OneEighth = 0b000,
+            OneQuarter = 0b001,
+            OneHalf = 0b010,
+            ThreeQuarters = 0b011,
+            SevenEighths = 0b100
+        ]
+    ],
+
+    /// Interrupt bits, shared by the IMSC, RIS, MIS and ICR registers
+    INT [
+        /// Overrun error
+        OE OFFSET(10) NUMBITS(1) [],
+        /// Break error
+        BE OFFSET(9) NUMBITS(1) [],
+        /// Parity error
+        PE OFFSET(8) NUMBITS(1) [],
+        /// Framing error
+        FE OFFSET(7) NUMBITS(1) [],
+        /// Receive timeout
+        RT OFFSET(6) NUMBITS(1) [],
+        /// Transmit
+        TX OFFSET(5) NUMBITS(1) [],
+        /// Receive
+        RX OFFSET(4) NUMBITS(1) [],
+        /// nUARTCTS modem
+        CTSM OFFSET(1) NUMBITS(1) []
+    ],
+
+    /// DMA Control Register
+    DMACR [
+        /// DMA on error
+        DMAONERR OFFSET(2) NUMBITS(1) [],
+        /// Transmit DMA enable
+        TXDMAE OFFSET(1) NUMBITS(1) [],
+        /// Receive DMA enable
+        RXDMAE OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub const PADDR: u64 = MMIO_BASE + 0x20_1000;
+
+#[allow(non_snake_case)]
+#[repr(C)]
+pub struct RegisterBlock {
+    pub DR: ReadWrite<u32, DR::Register>,         // 0x00
+    pub RSRECR: ReadWrite<u32, RSRECR::Register>, // 0x04
+    __reserved_0: [u32; 4],                       // 0x08
+    pub FR: ReadOnly<u32, FR::Register>,          // 0x18
+    __reserved_1: [u32; 2],                       // 0x1C
+    pub IBRD: ReadWrite<u32, IBRD::Register>,     // 0x24
+    pub FBRD: ReadWrite<u32, FBRD::Register>,     // 0x28
+    pub LCRH: ReadWrite<u32, LCRH::Register>,     // 0x2C
+    pub CR: ReadWrite<u32, CR::Register>,         // 0x30
+    pub IFLS: ReadWrite<u32, IFLS::Register>,     // 0x34
+    pub IMSC: ReadWrite<u32, INT::Register>,      // 0x38 - Interrupt Mask Set/Clear
+    pub RIS: ReadOnly<u32, INT::Register>,        // 0x3C - Raw Interrupt Status
+    pub MIS: ReadOnly<u32, INT::Register>,        // 0x40 - Masked Interrupt Status
+    pub ICR: WriteOnly<u32, INT::Register>,       // 0x44 - Interrupt Clear
+    pub DMACR: ReadWrite<u32, DMACR::Register>,   // 0x48
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct UART0 {
+    addr: *const u64,
+}
+
+impl From<u64> for UART0 {
+    fn from(vaddr: u64) -> UART0 {
+        assert_ne!(vaddr, 0);
+        UART0 {
+            addr: vaddr as *const u64,
+        }
+    }
+}
+
+unsafe impl Send for UART0 {}
+
+impl UART0 {
+    pub fn as_ptr(&self) -> *const RegisterBlock {
+        self.addr as *const _
+    }
+}
+
+impl Deref for UART0 {
+    type Target = RegisterBlock;
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.as_ptr() }
+    }
+}
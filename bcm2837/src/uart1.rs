@@ -16,6 +16,21 @@ register_bitfields! {
         MINI_UART_ENABLE OFFSET(0) NUMBITS(1) []
     ],
 
+    /// Mini Uart Interrupt Enable
+    AUX_MU_IER [
+        /// Not documented, but both bits must be set for the mini UART
+        /// to raise interrupts
+        INT_EN OFFSET(2) NUMBITS(2) [],
+
+        /// If this bit is set the interrupt line is asserted whenever
+        /// the transmit FIFO is empty.
+        TX_INT OFFSET(1) NUMBITS(1) [],
+
+        /// If this bit is set the interrupt line is asserted whenever
+        /// the receive FIFO holds at least 1 byte.
+        RX_INT OFFSET(0) NUMBITS(1) []
+    ],
+
     /// Mini Uart Interrupt Identify
     AUX_MU_IIR [
         /// Writing with bit 1 set will clear the receive FIFO
@@ -38,10 +53,19 @@ register_bitfields! {
 
     /// Mini Uart Line Status
     AUX_MU_LSR [
+        /// This bit is set if the transmit FIFO is empty and the
+        /// transmitter is idle.
+        TX_IDLE    OFFSET(6) NUMBITS(1) [],
+
         /// This bit is set if the transmit FIFO can accept at least
         /// one byte.
         TX_EMPTY   OFFSET(5) NUMBITS(1) [],
 
+        /// This bit is set if there was a receiver overrun, one or
+        /// more characters arrived while the receive FIFO was full.
+        /// Cleared each time this register is read.
+        RX_OVERRUN OFFSET(1) NUMBITS(1) [],
+
         /// This bit is set if the receive FIFO holds at least 1
         /// symbol.
         DATA_READY OFFSET(0) NUMBITS(1) []
@@ -80,7 +104,7 @@ pub struct RegisterBlock {
     pub AUX_ENABLES: ReadWrite<u32, AUX_ENABLES::Register>, // 0x04
     __reserved_1: [u32; 14],                                // 0x08
     pub AUX_MU_IO: ReadWrite<u32>,                          // 0x40 - Mini Uart I/O Data
-    pub AUX_MU_IER: WriteOnly<u32>,                         // 0x44 - Mini Uart Interrupt Enable
+    pub AUX_MU_IER: WriteOnly<u32, AUX_MU_IER::Register>,   // 0x44
     pub AUX_MU_IIR: WriteOnly<u32, AUX_MU_IIR::Register>,   // 0x48
     pub AUX_MU_LCR: WriteOnly<u32, AUX_MU_LCR::Register>,   // 0x4C
     pub AUX_MU_MCR: WriteOnly<u32>,                         // 0x50
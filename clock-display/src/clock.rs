@@ -3,7 +3,7 @@
 // - chrono https://github.com/chronotope/chrono
 
 use core::f32;
-use display::{Display, ObjectDrawing};
+use display::{Display, ObjectDrawing, PixelFormat};
 use embedded_graphics::coord::Coord;
 use embedded_graphics::prelude::*;
 use embedded_graphics::primitives::{Circle, Line};
@@ -111,7 +111,7 @@ impl Clock {
         self.hour_cd.set_value(digit);
     }
 
-    fn draw_second_digit(&self, display: &mut Display) {
+    fn draw_second_digit<F: PixelFormat>(&self, display: &mut Display<F>) {
         display.draw(
             Line::new(self.config.center, self.sec_cd.config().center)
                 .with_stroke(Some(self.sec_cd.config().background_fill_color.into()))
@@ -122,7 +122,7 @@ impl Clock {
         self.sec_cd.draw_object(display);
     }
 
-    fn draw_minute_digit(&self, display: &mut Display) {
+    fn draw_minute_digit<F: PixelFormat>(&self, display: &mut Display<F>) {
         display.draw(
             Line::new(self.config.center, self.min_cd.config().center)
                 .with_stroke(Some(self.min_cd.config().background_fill_color.into()))
@@ -133,7 +133,7 @@ impl Clock {
         self.min_cd.draw_object(display);
     }
 
-    fn draw_hour_digit(&self, display: &mut Display) {
+    fn draw_hour_digit<F: PixelFormat>(&self, display: &mut Display<F>) {
         display.draw(
             Line::new(self.config.center, self.hour_cd.config().center)
                 .with_stroke(Some(self.hour_cd.config().background_fill_color.into()))
@@ -144,7 +144,7 @@ impl Clock {
         self.hour_cd.draw_object(display);
     }
 
-    fn draw_outline_circles(&self, display: &mut Display) {
+    fn draw_outline_circles<F: PixelFormat>(&self, display: &mut Display<F>) {
         display.draw(
             Circle::new(self.config.center, self.config.radius)
                 //.with_fill(Some((0xFF, 0xFF, 0x00).into()))
@@ -167,7 +167,7 @@ impl Clock {
 }
 
 impl ObjectDrawing for Clock {
-    fn draw_object(&self, display: &mut Display) {
+    fn draw_object<F: PixelFormat>(&self, display: &mut Display<F>) {
         // draw back to front
         self.draw_outline_circles(display);
         self.draw_hour_digit(display);
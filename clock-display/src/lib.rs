@@ -12,6 +12,7 @@ use bcm2837_hal::bcm2837::dma::PADDR as DMA_PADDR;
 use bcm2837_hal::bcm2837::mbox::{
     BASE_OFFSET as MBOX_BASE_OFFSET, BASE_PADDR as MBOX_BASE_PADDR, MBOX,
 };
+use bcm2837_hal::bcm2837::sys_timer::PADDR as SYS_TIMER_PADDR;
 use bcm2837_hal::mailbox::{Channel, Mailbox};
 use bcm2837_hal::mailbox_msg::*;
 use bcm2837_hal::pmem::PMem as HALPMem;
@@ -23,9 +24,6 @@ mod macros;
 mod clock;
 mod render_thread;
 
-const DISPLAY_WIDTH: usize = 800;
-const DISPLAY_HEIGHT: usize = 480;
-
 pub fn handle_fault(badge: seL4_Word) {
     debug_println!("\n!!! Fault from badge 0x{:X}\n", badge);
 }
@@ -42,17 +40,16 @@ pub fn init(allocator: &mut Allocator, global_fault_ep_cap: seL4_CPtr) {
     debug_println!("Mapping DMA device");
     let dma_dev_pmem = map_device_pmem(allocator, DMA_PADDR, PAGE_BITS_4K as _, 0);
 
-    let display_backbuffer_size = DISPLAY_WIDTH * DISPLAY_HEIGHT * 4;
+    debug_println!("Mapping system timer device");
+    let sys_timer_dev_pmem = map_device_pmem(allocator, SYS_TIMER_PADDR, PAGE_BITS_4K as _, 0);
 
     // Size in bytes of the dma pool to reserve
     let dma_pool_size: seL4_Word =
         // 1 page for the mailbox buffer
         PAGE_SIZE_4K +
         // 1 page for the display scratchpad buffer, holds DMA control blocks/etc
-        PAGE_SIZE_4K +
-        // Pages for the contiguous display backbuffer, always 4 bpp
-        display_backbuffer_size as seL4_Word;
-    let dma_pool_size_pages = 1 + (dma_pool_size / PAGE_SIZE_4K);
+        PAGE_SIZE_4K;
+    let dma_pool_size_pages = dma_pool_size / PAGE_SIZE_4K;
 
     debug_println!(
         "Reserving DMA pool size 0x{:X} ({}), {} pages",
@@ -84,8 +81,8 @@ pub fn init(allocator: &mut Allocator, global_fault_ep_cap: seL4_CPtr) {
         mbox_buffer_pmem.size(),
     );
 
-    // Split off a page from the DMA pool for the display scratchpad buffer
-    let display_scratchpad_pmem = dma_pool_pmem.split(PAGE_SIZE_4K as _);
+    // The remaining page of the DMA pool is the display scratchpad buffer
+    let display_scratchpad_pmem = dma_pool_pmem;
 
     allocator.dma_cache_op(
         display_scratchpad_pmem.vaddr(),
@@ -101,9 +98,27 @@ pub fn init(allocator: &mut Allocator, global_fault_ep_cap: seL4_CPtr) {
         display_scratchpad_pmem.size(),
     );
 
-    // Use the remaining memory in the DMA pool as the display backbuffer
-    dma_pool_pmem.reduce_to(display_backbuffer_size);
-    let display_backbuffer_pmem = dma_pool_pmem;
+    let mut mbox: Mailbox = Mailbox::new(MBOX::from(vc_mbox_dev_pmem.vaddr()), mbox_buffer_pmem);
+
+    let fb_cfg = FramebufferCmd::from_preferred_mode(&mut mbox)
+        .expect("Failed to negotiate the display mode");
+
+    let (display_framebuffer_pmem, fb_resp) = request_framebuffer(allocator, &mut mbox, &fb_cfg);
+
+    debug_println!("Mapped in VideoCore GPU pmem");
+    debug_println!(
+        "  vaddr = 0x{:X} paddr = 0x{:X} size = 0x{:X}",
+        display_framebuffer_pmem.vaddr(),
+        display_framebuffer_pmem.paddr(),
+        display_framebuffer_pmem.size(),
+    );
+
+    // Pages for the contiguous display backbuffer, always 4 bpp
+    let display_backbuffer_size = (fb_resp.phy_width * fb_resp.phy_height * 4) as usize;
+    let display_backbuffer_pages = 1 + (display_backbuffer_size / PAGE_SIZE_4K as usize);
+
+    let mut display_backbuffer_pmem = reserve_dma_pool(allocator, display_backbuffer_pages);
+    display_backbuffer_pmem.reduce_to(display_backbuffer_size);
 
     allocator.dma_cache_op(
         display_backbuffer_pmem.vaddr(),
@@ -119,26 +134,8 @@ pub fn init(allocator: &mut Allocator, global_fault_ep_cap: seL4_CPtr) {
         display_backbuffer_pmem.size(),
     );
 
-    let mut mbox: Mailbox = Mailbox::new(MBOX::from(vc_mbox_dev_pmem.vaddr()), mbox_buffer_pmem);
-
-    let mut framebuffer_pitch: usize = 0;
-    let mut framebuffer_pixel_order: PixelOrder = PixelOrder::RGB;
-    let display_framebuffer_pmem = request_framebuffer(
-        allocator,
-        &mut mbox,
-        DISPLAY_WIDTH,
-        DISPLAY_HEIGHT,
-        &mut framebuffer_pitch,
-        &mut framebuffer_pixel_order,
-    );
-
-    debug_println!("Mapped in VideoCore GPU pmem");
-    debug_println!(
-        "  vaddr = 0x{:X} paddr = 0x{:X} size = 0x{:X}",
-        display_framebuffer_pmem.vaddr(),
-        display_framebuffer_pmem.paddr(),
-        display_framebuffer_pmem.size(),
-    );
+    // The render thread takes over the mailbox to sample the temperature
+    let (_, mbox_buffer_pmem) = mbox.free();
 
     // Create an IPC buffer / page of memory to store the thread data parameters
     let thread_data_vaddr = allocator
@@ -148,11 +145,15 @@ pub fn init(allocator: &mut Allocator, global_fault_ep_cap: seL4_CPtr) {
     // Fill the thread config parameters
     let thread_data = unsafe { &mut *(thread_data_vaddr as *mut render_thread::Config) };
     thread_data.dma_vaddr = dma_dev_pmem.vaddr();
+    thread_data.mbox_vaddr = vc_mbox_dev_pmem.vaddr();
+    thread_data.mbox_buffer_pmem = mbox_buffer_pmem;
+    thread_data.sys_timer_vaddr = sys_timer_dev_pmem.vaddr();
     thread_data.scratchpad_pmem = display_scratchpad_pmem;
-    thread_data.fb_width = DISPLAY_WIDTH;
-    thread_data.fb_height = DISPLAY_HEIGHT;
-    thread_data.fb_pitch = framebuffer_pitch;
-    thread_data.fb_pixel_order = framebuffer_pixel_order;
+    thread_data.fb_width = fb_resp.phy_width as _;
+    thread_data.fb_height = fb_resp.phy_height as _;
+    thread_data.fb_pitch = fb_resp.pitch as _;
+    thread_data.fb_depth = fb_resp.depth;
+    thread_data.fb_pixel_order = fb_resp.pixel_order;
     thread_data.framebuffer_pmem = display_framebuffer_pmem;
     thread_data.backbuffer_pmem = display_backbuffer_pmem;
 
@@ -176,22 +177,10 @@ pub fn init(allocator: &mut Allocator, global_fault_ep_cap: seL4_CPtr) {
 fn request_framebuffer(
     allocator: &mut Allocator,
     mbox: &mut Mailbox,
-    width: usize,
-    height: usize,
-    framebuffer_pitch: &mut usize,
-    framebuffer_pixel_order: &mut PixelOrder,
-) -> HALPMem {
-    let fb_cfg = FramebufferCmd {
-        phy_width: width as _,
-        phy_height: height as _,
-        virt_width: width as _,
-        virt_height: height as _,
-        x_offset: 0,
-        y_offset: 0,
-    };
-
+    fb_cfg: &FramebufferCmd,
+) -> (HALPMem, FramebufferResp) {
     let resp: Resp = mbox
-        .call(Channel::Prop, &fb_cfg)
+        .call(Channel::Prop, fb_cfg)
         .expect("Mailbox::call failed on FramebufferCmd");
 
     let fb_resp = if let Resp::FramebufferResp(r) = resp {
@@ -200,8 +189,8 @@ fn request_framebuffer(
         panic!("Mailbox returned an invalid response {:#?}", resp);
     };
 
-    assert_eq!(fb_resp.phy_width as usize, width);
-    assert_eq!(fb_resp.phy_height as usize, height);
+    assert_eq!(fb_resp.phy_width, fb_cfg.phy_width);
+    assert_eq!(fb_resp.phy_height, fb_cfg.phy_height);
 
     // TODO - should be virt, pitch * virt_height
     let mem_size_bytes = (fb_resp.phy_height * fb_resp.pitch) as seL4_Word;
@@ -222,15 +211,14 @@ fn request_framebuffer(
         DMACacheOp::CleanInvalidate,
     );
 
-    *framebuffer_pitch = fb_resp.pitch as _;
-    *framebuffer_pixel_order = fb_resp.pixel_order;
-
-    HALPMem::new(
+    let framebuffer_pmem = HALPMem::new(
         gpu_pmem.vaddr,
         // Use the bus address as given by the VideoCore
         fb_resp.bus_paddr,
         mem_size_bytes as _,
-    )
+    );
+
+    (framebuffer_pmem, fb_resp)
 }
 
 // TODO - result/error-handling
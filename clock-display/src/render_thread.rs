@@ -1,9 +1,16 @@
 use bcm2837_hal::bcm2837::dma::{DMA, ENABLE};
+use bcm2837_hal::bcm2837::mbox::MBOX;
+use bcm2837_hal::bcm2837::sys_timer::SYSTIMER;
 use bcm2837_hal::dma::DmaExt;
+use bcm2837_hal::mailbox::Mailbox;
 use bcm2837_hal::mailbox_msg::PixelOrder;
 use bcm2837_hal::pmem::PMem;
-use display::{Display, ObjectDrawing};
+use bcm2837_hal::thermal::ThermalMonitor;
+use bcm2837_hal::time::Duration;
+use bcm2837_hal::timer::MonoTimer;
+use display::{Display, ObjectDrawing, Rgba8888};
 use embedded_graphics::coord::Coord;
+use gui::{BarGraph, BarGraphConfig};
 use rgb::RGB8;
 use sel4_sys::seL4_Word;
 
@@ -19,10 +26,14 @@ pub const STACK_SIZE_PAGES: usize = 8;
 #[derive(Debug)]
 pub struct Config {
     pub dma_vaddr: seL4_Word,
+    pub mbox_vaddr: seL4_Word,
+    pub mbox_buffer_pmem: PMem,
+    pub sys_timer_vaddr: seL4_Word,
     pub scratchpad_pmem: PMem,
     pub fb_width: usize,
     pub fb_height: usize,
     pub fb_pitch: usize,
+    pub fb_depth: u32,
     pub fb_pixel_order: PixelOrder,
     pub framebuffer_pmem: PMem,
     pub backbuffer_pmem: PMem,
@@ -45,11 +56,12 @@ pub fn run(config_vaddr: seL4_Word) {
     dma_parts.enable.ENABLE.write(ENABLE::EN0::SET);
     dma_parts.ch0.reset();
 
-    let mut display = Display::new(
+    let mut display: Display<Rgba8888> = Display::new(
         dma_parts.ch0,
         config.fb_width,
         config.fb_height,
         config.fb_pitch,
+        config.fb_depth,
         config.fb_pixel_order,
         config.scratchpad_pmem,
         config.framebuffer_pmem,
@@ -63,12 +75,30 @@ pub fn run(config_vaddr: seL4_Word) {
         outline_color: RGB8::new(0xFF, 0xFF, 0xFF),
     });
 
+    let mut mbox = Mailbox::new(MBOX::from(config.mbox_vaddr), config.mbox_buffer_pmem);
+    let timer = MonoTimer::new(SYSTIMER::from(config.sys_timer_vaddr));
+
+    let mut thermal_monitor = ThermalMonitor::new(&mut mbox, timer, Duration::from_secs(1))
+        .expect("Failed to create the ThermalMonitor");
+
+    // Temperature as a fraction of the throttling temperature
+    let mut temp_bar = BarGraph::new(BarGraphConfig {
+        top_left: Coord::new(display.width() as i32 - 100, 40),
+        bottom_right: Coord::new(display.width() as i32 - 40, display.height() as i32 - 40),
+        background_color: RGB8::new(0x00, 0x00, 0x00),
+        fill_color: RGB8::new(0xF0, 0x5F, 0x1F),
+        text_color: RGB8::new(0xFF, 0xFF, 0xFF),
+        stroke_color: RGB8::new(0xFF, 0xFF, 0xFF),
+        stroke_width: 2,
+    });
+    temp_bar.set_value(thermal_monitor.load());
+
     let mut hour: u32 = 3;
     let mut min: u32 = 45;
     let mut sec: u32 = 0;
 
     // Clear back and front buffers
-    display.clear_screen();
+    display.clear_screen().expect("Failed to clear the screen");
 
     loop {
         // Clear the backbuffer
@@ -90,8 +120,17 @@ pub fn run(config_vaddr: seL4_Word) {
 
         clock.update_digits(hour, min, sec);
 
+        if thermal_monitor
+            .poll(&mut mbox)
+            .expect("Failed to sample the temperature")
+            .is_some()
+        {
+            temp_bar.set_value(thermal_monitor.load());
+        }
+
         clock.draw_object(&mut display);
+        temp_bar.draw_object(&mut display);
 
-        display.swap_buffers();
+        display.swap_buffers().expect("Failed to swap buffers");
     }
 }
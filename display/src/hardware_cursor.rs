@@ -0,0 +1,145 @@
+use bcm2837_hal::mailbox::{Channel, Error, Mailbox};
+use bcm2837_hal::mailbox_msg::{
+    CursorCoord, SetCursorInfoCmd, SetCursorInfoResp, SetCursorStateCmd, SetCursorStateResp, Tag,
+};
+use bcm2837_hal::pmem::PMem;
+use embedded_graphics::drawable::Pixel;
+use embedded_graphics::Drawing;
+
+use display_color::DisplayColor;
+
+/// Largest cursor image the firmware supports, in pixels
+pub const MAX_CURSOR_SIZE: usize = 64;
+
+/// Hardware cursor, the image is drawn into a buffer read by the VideoCore
+///
+/// Pixels not drawn to are transparent
+#[derive(Debug)]
+pub struct HardwareCursor {
+    buffer: PMem,
+    width: usize,
+    height: usize,
+    hotspot_x: u32,
+    hotspot_y: u32,
+}
+
+impl HardwareCursor {
+    /// Expects a DMA buffer of at least `width * height * 4` bytes
+    pub fn new(buffer: PMem, width: usize, height: usize, hotspot_x: u32, hotspot_y: u32) -> Self {
+        assert!(width <= MAX_CURSOR_SIZE && height <= MAX_CURSOR_SIZE);
+        assert!(buffer.size() >= width * height * 4);
+        assert!((hotspot_x as usize) < width && (hotspot_y as usize) < height);
+
+        let mut cursor = HardwareCursor {
+            buffer,
+            width,
+            height,
+            hotspot_x,
+            hotspot_y,
+        };
+
+        cursor.clear();
+
+        cursor
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Makes the whole image transparent
+    pub fn clear(&mut self) {
+        for pixel in self
+            .buffer
+            .as_mut_slice::<u32>(self.width * self.height)
+            .iter_mut()
+        {
+            *pixel = 0;
+        }
+    }
+
+    /// Gives the image to the firmware, must be called after drawing
+    pub fn upload(&self, mbox: &mut Mailbox) -> Result<(), Error> {
+        let resp: SetCursorInfoResp = mbox
+            .call_batch(
+                Channel::Prop,
+                &SetCursorInfoCmd {
+                    width: self.width as _,
+                    height: self.height as _,
+                    hotspot_x: self.hotspot_x,
+                    hotspot_y: self.hotspot_y,
+                    pixels: self.buffer,
+                },
+            )?
+            .get()?;
+
+        if !resp.is_valid {
+            return Err(Error::InvalidResponse(Tag::SetCursorInfo));
+        }
+
+        Ok(())
+    }
+
+    /// Shows the cursor with its hotspot at `x`, `y`
+    pub fn move_to(
+        &mut self,
+        mbox: &mut Mailbox,
+        x: u32,
+        y: u32,
+        coord: CursorCoord,
+    ) -> Result<(), Error> {
+        set_state(mbox, true, x, y, coord)
+    }
+
+    pub fn hide(&mut self, mbox: &mut Mailbox) -> Result<(), Error> {
+        set_state(mbox, false, 0, 0, CursorCoord::Display)
+    }
+}
+
+fn set_state(
+    mbox: &mut Mailbox,
+    enable: bool,
+    x: u32,
+    y: u32,
+    coord: CursorCoord,
+) -> Result<(), Error> {
+    let resp: SetCursorStateResp = mbox
+        .call_batch(
+            Channel::Prop,
+            &SetCursorStateCmd {
+                enable,
+                x,
+                y,
+                coord,
+            },
+        )?
+        .get()?;
+
+    if !resp.is_valid {
+        return Err(Error::InvalidResponse(Tag::SetCursorState));
+    }
+
+    Ok(())
+}
+
+impl Drawing<DisplayColor> for HardwareCursor {
+    fn draw<T>(&mut self, item_pixels: T)
+    where
+        T: Iterator<Item = Pixel<DisplayColor>>,
+    {
+        let pixels = self.buffer.as_mut_slice::<u32>(self.width * self.height);
+
+        for Pixel(coord, color) in item_pixels {
+            if coord[0] as usize >= self.width || coord[1] as usize >= self.height {
+                continue;
+            }
+
+            // ARGB, opaque
+            pixels[(coord[1] as usize * self.width) + coord[0] as usize] = color.as_alt();
+        }
+    }
+}
@@ -3,32 +3,37 @@
 // TODO
 // - fix the broken DMA logic when src == scratchpad_buffer, dst == backbuffer?
 // - use embedded-graphics types/traits on Display (top-left()/etc)
-// - configs for single/double buffer modes
 
 extern crate bcm2837_hal;
 extern crate embedded_graphics;
 extern crate rgb;
 
 mod display_color;
+mod hardware_cursor;
+mod pixel_format;
 
 use bcm2837_hal::dma;
-use bcm2837_hal::mailbox_msg::PixelOrder;
+use bcm2837_hal::mailbox::{self, Channel, Mailbox};
+use bcm2837_hal::mailbox_msg::{PixelOrder, SetVirtOffsetCmd, WaitForVsyncCmd};
 use bcm2837_hal::pmem::PMem;
+use core::marker::PhantomData;
 use core::ptr;
 use embedded_graphics::drawable::Pixel;
 use embedded_graphics::Drawing;
 
 pub use display_color::DisplayColor;
+pub use hardware_cursor::{HardwareCursor, MAX_CURSOR_SIZE};
+pub use pixel_format::{PixelFormat, Rgb332, Rgb565, Rgb888, Rgba8888};
 
 // TODO - until I figure out how to cleanly use embedded-graphics IntoIterator
 // to combine primitives,
 // this can be used to pass around a mut Display
 pub trait ObjectDrawing {
-    fn draw_object(&self, display: &mut Display);
+    fn draw_object<F: PixelFormat>(&self, display: &mut Display<F>);
 }
 
 /// Offset into the scratchpad buffer used to store
-/// the DMA control block
+/// the DMA control blocks
 const SP_CONTROL_BLOCK_OFFSET: usize = 0;
 
 /// Offset into the scratchpad buffer used to store
@@ -43,8 +48,9 @@ const NUM_CONTROL_BLOCKS: usize = (PAGE_SIZE_4K / dma::CONTROL_BLOCK_SIZE) - 1;
 /// 128 bit writes are supported
 const NUM_FILL_WORDS: usize = 4;
 
+/// Display with a framebuffer of pixel format `F`
 #[derive(Debug)]
-pub struct Display {
+pub struct Display<F: PixelFormat = Rgba8888> {
     dma: dma::Channel,
     width: usize,
     height: usize,
@@ -53,11 +59,24 @@ pub struct Display {
     scratchpad: PMem,
     /// Control blocks and fill words are split pmem from the provided
     /// scratchpad
-    control_blocks: PMem,
+    control_blocks: dma::ControlBlockPool,
     fill_words: PMem,
     /// Framebuffer is also the front buffer
     framebuffer: PMem,
     backbuffer: PMem,
+    /// Bytes per row of the backbuffer
+    backbuffer_pitch: usize,
+    page_flip: Option<PageFlip>,
+    _format: PhantomData<F>,
+}
+
+/// Page flipping state, the framebuffer holds two pages and the
+/// hidden one is the backbuffer
+#[derive(Debug)]
+struct PageFlip {
+    mbox: Mailbox,
+    wait_for_vsync: bool,
+    back_page: usize,
 }
 
 // TODO - move value to a param here,
@@ -72,26 +91,30 @@ enum TransferOp {
     CopyBackToFront,
 }
 
-impl Display {
+impl<F: PixelFormat> Display<F> {
     /// Expects to be given at least 1 4K page of DMA scratchpad mem
+    ///
+    /// `depth` is the framebuffer depth granted by the firmware, it must
+    /// match the pixel format
     pub fn new(
         dma: dma::Channel,
         width: usize,
         height: usize,
         pitch: usize,
+        depth: u32,
         pixel_order: PixelOrder,
         scratchpad: PMem,
         framebuffer: PMem,
         backbuffer: PMem,
     ) -> Self {
-        assert_eq!(
-            dma.is_lite(),
-            false,
-            "Can't use a lite DMA engine for 2D transfers"
-        );
         assert_ne!(width, 0);
         assert_ne!(height, 0);
-        assert_ne!(pitch, 0);
+        assert_eq!(
+            depth,
+            F::DEPTH,
+            "Framebuffer depth doesn't match the pixel format"
+        );
+        assert!(pitch >= width * F::BYTES_PER_PIXEL);
 
         let control_blocks_pmem = PMem::new(
             scratchpad.vaddr() + SP_CONTROL_BLOCK_OFFSET as u64,
@@ -99,13 +122,6 @@ impl Display {
             NUM_CONTROL_BLOCKS * dma::CONTROL_BLOCK_SIZE,
         );
 
-        for cb in control_blocks_pmem
-            .as_mut_slice::<dma::ControlBlock>(NUM_CONTROL_BLOCKS)
-            .iter_mut()
-        {
-            cb.init();
-        }
-
         let fill_words_pmem = PMem::new(
             scratchpad.vaddr() + SP_FILL_WORDS_OFFSET as u64,
             scratchpad.paddr() + SP_FILL_WORDS_OFFSET as u32,
@@ -119,13 +135,65 @@ impl Display {
             pitch,
             pixel_order,
             scratchpad,
-            control_blocks: control_blocks_pmem,
+            // The control blocks region is only used by the pool
+            control_blocks: unsafe { dma::ControlBlockPool::new(control_blocks_pmem) },
             fill_words: fill_words_pmem,
             framebuffer,
             backbuffer,
+            backbuffer_pitch: width * F::BYTES_PER_PIXEL,
+            page_flip: None,
+            _format: PhantomData,
         }
     }
 
+    /// Page flipping display, renders into the hidden half of a framebuffer
+    /// allocated with a virtual height of twice the `height` and flips
+    /// with a `SetVirtOffsetCmd` instead of copying the backbuffer
+    ///
+    /// Request the framebuffer with `FramebufferCmd::page_flipped()`
+    pub fn new_page_flipped(
+        dma: dma::Channel,
+        width: usize,
+        height: usize,
+        pitch: usize,
+        depth: u32,
+        pixel_order: PixelOrder,
+        scratchpad: PMem,
+        framebuffer: PMem,
+        mbox: Mailbox,
+        wait_for_vsync: bool,
+    ) -> Self {
+        assert!(
+            framebuffer.size() >= 2 * height * pitch,
+            "Framebuffer must hold two pages"
+        );
+
+        // Page 0 is displayed first
+        let back_page = 1;
+        let backbuffer = framebuffer_page(&framebuffer, height, pitch, back_page);
+
+        let mut display = Self::new(
+            dma,
+            width,
+            height,
+            pitch,
+            depth,
+            pixel_order,
+            scratchpad,
+            framebuffer,
+            backbuffer,
+        );
+
+        display.backbuffer_pitch = pitch;
+        display.page_flip = Some(PageFlip {
+            mbox,
+            wait_for_vsync,
+            back_page,
+        });
+
+        display
+    }
+
     pub fn width(&self) -> usize {
         self.width
     }
@@ -137,29 +205,14 @@ impl Display {
     /// Sets a pixel in the backbuffer
     /// RGB b[0] = Red, b[1] = Green, b[2] = Blue, b[3] = NA
     pub fn set_pixel(&mut self, x: u32, y: u32, value: u32) {
-        let color_word: u32 = if self.pixel_order == PixelOrder::RGB {
-            value
-        } else {
-            DisplayColor::from(value).as_alt()
-        };
-
-        // The frontbuffer, may not be contiguous so must use pitch (pitch >= bpp*width)
-        //let offset = (y * (self.pitch / 4)) + x;
-        //unsafe { ptr::write(self.framebuffer.as_mut_ptr()
-        //.offset(offset as _), color_word) };
-
-        // The backbuffer is contiguous
-        let offset = (y * self.width as u32) + x;
-        unsafe {
-            ptr::write(
-                self.backbuffer.as_mut_ptr::<u32>().offset(offset as _),
-                color_word,
-            )
-        };
+        let pixel = F::encode(DisplayColor::from(value), self.pixel_order);
+        self.write_pixel(x as _, y as _, pixel);
     }
 
     /// Clears the backbuffer and the frontbuffer
-    pub fn clear_screen(&mut self) {
+    ///
+    /// Only a page flipping display can fail, see `swap_buffers()`
+    pub fn clear_screen(&mut self) -> Result<(), mailbox::Error> {
         self.set_scratchpad_src_fill_words(0_u32.into());
 
         // self.dma_transfer(TransferOp::FillFront);
@@ -168,7 +221,14 @@ impl Display {
         // self.dma_transfer(TransferOp::FillBack);
         // Clear it manually for now
         self.fill_pixels(0_u32.into());
-        self.swap_buffers();
+        self.swap_buffers()?;
+
+        // The previous front page is now the backbuffer
+        if self.page_flip.is_some() {
+            self.fill_pixels(0_u32.into());
+        }
+
+        Ok(())
     }
 
     /// Clears the backbuffer
@@ -183,8 +243,16 @@ impl Display {
     }
 
     /// Swap/copy the backbuffer to the frontbuffer/framebuffer
-    pub fn swap_buffers(&mut self) {
-        self.dma_transfer(TransferOp::CopyBackToFront);
+    ///
+    /// Only a page flipping display can fail, when the mailbox call that
+    /// flips the pages fails
+    pub fn swap_buffers(&mut self) -> Result<(), mailbox::Error> {
+        if self.page_flip.is_some() {
+            self.flip_pages()
+        } else {
+            self.dma_transfer(TransferOp::CopyBackToFront);
+            Ok(())
+        }
     }
 
     /// Fills the backbuffer with a color using a DMA transfer
@@ -199,45 +267,106 @@ impl Display {
 
     /// Fills the backbuffer with a color pixel by pixel
     pub fn fill_pixels(&mut self, color: DisplayColor) {
-        // Since the backbuffer is contiguous, we can use memset/alike
-        let buffer = self
-            .backbuffer
-            .as_mut_slice::<u32>(self.backbuffer.size() / 4);
-        let color_word: u32 = if self.pixel_order == PixelOrder::RGB {
-            color.into()
-        } else {
-            color.as_alt()
+        let pixel = F::encode(color, self.pixel_order);
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                self.write_pixel(x, y, pixel);
+            }
+        }
+    }
+
+    /// Writes an encoded pixel to the backbuffer
+    fn write_pixel(&mut self, x: usize, y: usize, pixel: u32) {
+        // The backbuffer is contiguous, unless it's a framebuffer page
+        let offset = (y * self.backbuffer_pitch) + (x * F::BYTES_PER_PIXEL);
+
+        unsafe {
+            let dst = self.backbuffer.as_mut_ptr::<u8>().offset(offset as _);
+            match F::BYTES_PER_PIXEL {
+                1 => ptr::write(dst, pixel as u8),
+                2 => ptr::write(dst as *mut u16, pixel as u16),
+                3 => {
+                    ptr::write(dst, pixel as u8);
+                    ptr::write(dst.offset(1), (pixel >> 8) as u8);
+                    ptr::write(dst.offset(2), (pixel >> 16) as u8);
+                }
+                _ => ptr::write(dst as *mut u32, pixel),
+            }
+        }
+    }
+
+    /// Displays the backbuffer page, the previous front page becomes
+    /// the backbuffer
+    fn flip_pages(&mut self) -> Result<(), mailbox::Error> {
+        let back_page = {
+            let pf = match self.page_flip {
+                Some(ref mut pf) => pf,
+                None => return Ok(()),
+            };
+
+            let offset = SetVirtOffsetCmd {
+                x: 0,
+                y: (pf.back_page * self.height) as _,
+            };
+
+            if pf.wait_for_vsync {
+                pf.mbox.call(Channel::Prop, &(WaitForVsyncCmd, offset))
+            } else {
+                pf.mbox.call(Channel::Prop, &offset)
+            }?;
+
+            pf.back_page ^= 1;
+            pf.back_page
         };
 
-        for word in buffer.iter_mut() {
-            *word = color_word;
+        self.backbuffer = framebuffer_page(&self.framebuffer, self.height, self.pitch, back_page);
+
+        Ok(())
+    }
+
+    /// The displayed buffer, the current front page when page flipping
+    fn frontbuffer(&self) -> PMem {
+        match self.page_flip {
+            Some(ref pf) => {
+                framebuffer_page(&self.framebuffer, self.height, self.pitch, pf.back_page ^ 1)
+            }
+            None => self.framebuffer,
         }
     }
 
     /// Constructs the DMA source fill words in the internal scratchpad buffer
+    ///
+    /// NOTE: 24 bit pixels don't evenly divide the fill words, the pattern
+    /// is only correct for the first 5 pixels of a 16 byte burst
     fn set_scratchpad_src_fill_words(&mut self, color: DisplayColor) {
-        let fill_words = self.fill_words.as_mut_slice::<u32>(NUM_FILL_WORDS);
+        let pixel = F::encode(color, self.pixel_order);
+        let fill_bytes = self.fill_words.as_mut_slice::<u8>(NUM_FILL_WORDS * 4);
 
-        for w in fill_words.iter_mut() {
-            *w = color.into();
+        for (i, b) in fill_bytes.iter_mut().enumerate() {
+            *b = (pixel >> ((i % F::BYTES_PER_PIXEL) * 8)) as u8;
         }
     }
 
     fn dma_transfer(&mut self, op: TransferOp) {
         // Stride, in bytes, is a signed inc/dec applied after end of each row
-        let bbp: usize = 4;
+        let bbp: usize = F::BYTES_PER_PIXEL;
         let frontbuffer_stride = (self.pitch - (self.width * bbp)) as u32;
-        let backbuffer_stride = 0;
+        // Zero unless the backbuffer is a framebuffer page
+        let backbuffer_stride = (self.backbuffer_pitch - (self.width * bbp)) as u32;
+
+        // The scratchpad words are re-read for every row
+        let fill_stride = 0;
 
-        // Both the backbuffer and the scratchpad words are contiguous
-        let src_stride = 0;
+        let frontbuffer = self.frontbuffer();
 
-        let (src_inc, src_paddr, dst_paddr, dst_stride) = match op {
+        let (src_inc, src_paddr, src_stride, dst_paddr, dst_stride) = match op {
             TransferOp::FillBack => {
                 // Filling the backbuffer with the contents of the scratchpad words
                 (
                     false,
                     self.fill_words.paddr(),
+                    fill_stride,
                     self.backbuffer.paddr(),
                     backbuffer_stride,
                 )
@@ -247,7 +376,8 @@ impl Display {
                 (
                     false,
                     self.fill_words.paddr(),
-                    self.framebuffer.paddr(),
+                    fill_stride,
+                    frontbuffer.paddr(),
                     frontbuffer_stride,
                 )
             }
@@ -256,7 +386,8 @@ impl Display {
                 (
                     true,
                     self.backbuffer.paddr(),
-                    self.framebuffer.paddr(),
+                    backbuffer_stride,
+                    frontbuffer.paddr(),
                     frontbuffer_stride,
                 )
             }
@@ -290,30 +421,43 @@ impl Display {
             no_wide_bursts: false,
         };
 
-        let control_blocks = self
-            .control_blocks
-            .as_mut_slice::<dma::ControlBlock>(NUM_CONTROL_BLOCKS);
-
-        // Apply control block configuration to the control block
-        control_blocks[0].config(
-            &cb_config,
-            src_paddr,
-            dst_paddr,
-            src_stride as _,
-            dst_stride as _,
-            0,
-        );
-
-        // Wait for DMA to be ready, then do the transfer
+        // Wait for DMA to be ready, the previous transfer's control blocks
+        // can then be reused
         while self.dma.is_busy() == true {}
-        self.dma.start(self.control_blocks.paddr());
-        self.dma.wait();
+        self.control_blocks.reset();
+
+        let list = {
+            let mut chain = self.control_blocks.chain::<dma::Channel>();
+            chain
+                .push(
+                    &cb_config,
+                    src_paddr,
+                    dst_paddr,
+                    src_stride as _,
+                    dst_stride as _,
+                )
+                .expect("Failed to allocate DMA control block");
+            chain.finish().expect("Empty DMA control block chain")
+        };
+
+        // Do the transfer
+        let result = self.dma.start_list(list).wait();
 
-        assert_eq!(self.dma.errors(), false, "DMA errors present");
+        assert!(result.is_ok(), "DMA errors present");
     }
 }
 
-impl Drawing<DisplayColor> for Display {
+/// One of the two pages of a page flipping framebuffer
+fn framebuffer_page(framebuffer: &PMem, height: usize, pitch: usize, page: usize) -> PMem {
+    let offset = page * height * pitch;
+    PMem::new(
+        framebuffer.vaddr() + offset as u64,
+        framebuffer.paddr() + offset as u32,
+        height * pitch,
+    )
+}
+
+impl<F: PixelFormat> Drawing<DisplayColor> for Display<F> {
     fn draw<T>(&mut self, item_pixels: T)
     where
         T: Iterator<Item = Pixel<DisplayColor>>,
@@ -323,7 +467,8 @@ impl Drawing<DisplayColor> for Display {
                 continue;
             }
 
-            self.set_pixel(coord[0], coord[1], u32::from(color));
+            let pixel = F::encode(color, self.pixel_order);
+            self.write_pixel(coord[0] as _, coord[1] as _, pixel);
         }
     }
 }
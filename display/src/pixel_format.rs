@@ -0,0 +1,94 @@
+use bcm2837_hal::mailbox_msg::{PixelOrder, PALETTE_LEN};
+
+use display_color::DisplayColor;
+
+/// Framebuffer pixel format
+///
+/// `PixelOrder::RGB` puts red in the least significant bits of a pixel,
+/// `PixelOrder::BGR` puts blue there
+pub trait PixelFormat {
+    /// Framebuffer depth to request, in bits per pixel
+    const DEPTH: u32;
+    const BYTES_PER_PIXEL: usize;
+
+    /// Encodes a color into the low `BYTES_PER_PIXEL` bytes
+    fn encode(color: DisplayColor, pixel_order: PixelOrder) -> u32;
+}
+
+/// 8 bits per pixel, indexes the palette from `Rgb332::palette()`
+#[derive(Debug, Copy, Clone)]
+pub struct Rgb332;
+
+/// 16 bits per pixel
+#[derive(Debug, Copy, Clone)]
+pub struct Rgb565;
+
+/// 24 bits per pixel
+#[derive(Debug, Copy, Clone)]
+pub struct Rgb888;
+
+/// 32 bits per pixel, the alpha channel is always opaque
+#[derive(Debug, Copy, Clone)]
+pub struct Rgba8888;
+
+impl Rgb332 {
+    /// Palette to load with a `SetPaletteCmd`
+    pub fn palette() -> [u32; PALETTE_LEN] {
+        let mut palette = [0; PALETTE_LEN];
+
+        for (index, entry) in palette.iter_mut().enumerate() {
+            let r = ((index >> 5) & 0x7) * 0xFF / 0x7;
+            let g = ((index >> 2) & 0x7) * 0xFF / 0x7;
+            let b = (index & 0x3) * 0xFF / 0x3;
+            *entry = (r | (g << 8) | (b << 16)) as u32;
+        }
+
+        palette
+    }
+}
+
+impl PixelFormat for Rgb332 {
+    const DEPTH: u32 = 8;
+    const BYTES_PER_PIXEL: usize = 1;
+
+    /// The palette takes care of the pixel order
+    fn encode(color: DisplayColor, _pixel_order: PixelOrder) -> u32 {
+        let c = color.into_inner();
+        (u32::from(c.r >> 5) << 5) | (u32::from(c.g >> 5) << 2) | u32::from(c.b >> 6)
+    }
+}
+
+impl PixelFormat for Rgb565 {
+    const DEPTH: u32 = 16;
+    const BYTES_PER_PIXEL: usize = 2;
+
+    fn encode(color: DisplayColor, pixel_order: PixelOrder) -> u32 {
+        let c = color.into_inner();
+        let (low, high) = match pixel_order {
+            PixelOrder::RGB => (c.r, c.b),
+            PixelOrder::BGR => (c.b, c.r),
+        };
+        u32::from(low >> 3) | (u32::from(c.g >> 2) << 5) | (u32::from(high >> 3) << 11)
+    }
+}
+
+impl PixelFormat for Rgb888 {
+    const DEPTH: u32 = 24;
+    const BYTES_PER_PIXEL: usize = 3;
+
+    fn encode(color: DisplayColor, pixel_order: PixelOrder) -> u32 {
+        Rgba8888::encode(color, pixel_order) & 0x00_FF_FF_FF
+    }
+}
+
+impl PixelFormat for Rgba8888 {
+    const DEPTH: u32 = 32;
+    const BYTES_PER_PIXEL: usize = 4;
+
+    fn encode(color: DisplayColor, pixel_order: PixelOrder) -> u32 {
+        match pixel_order {
+            PixelOrder::RGB => color.into(),
+            PixelOrder::BGR => color.as_alt(),
+        }
+    }
+}
@@ -158,6 +158,8 @@ pub fn init(allocator: &mut Allocator, _global_fault_ep_cap: seL4_CPtr) {
         virt_height: display_height,
         x_offset: 0,
         y_offset: 0,
+        depth: 32,
+        pixel_order: PixelOrder::RGB,
     };
 
     let resp: Resp = mbox
@@ -251,13 +253,8 @@ pub fn init(allocator: &mut Allocator, _global_fault_ep_cap: seL4_CPtr) {
         0,
     );
 
-    // Pick DMA channel 0
+    // Pick DMA channel 0, 2D transfers need a full (non-lite) channel
     let mut dma_channel = dma_parts.ch0;
-    assert_eq!(
-        dma_channel.is_lite(),
-        false,
-        "Can't use a lite DMA engine for 2D transfers"
-    );
 
     debug_println!("Enabling DMA channel 0");
 
@@ -278,5 +275,63 @@ pub fn init(allocator: &mut Allocator, _global_fault_ep_cap: seL4_CPtr) {
 
     assert_eq!(dma_channel.errors(), false, "DMA errors present");
 
+    // A linear fill on a lite channel, using the owned Transfer API
+    let fill_pmem = allocator
+        .pmem_new_dma_page(None)
+        .expect("Failed to allocate pmem");
+
+    allocator.dma_cache_op(
+        fill_pmem.vaddr,
+        PAGE_SIZE_4K as _,
+        DMACacheOp::CleanInvalidate,
+    );
+
+    // Fill the whole scratchpad, the transfer reads it in bursts
+    let mut fill_words = unsafe {
+        Buffer::new(HALPMem::new(
+            scratchpad_vaddr as _,
+            scratchpad_paddr,
+            CONTROL_BLOCK_SIZE,
+        ))
+    };
+    for word in fill_words.as_mut_slice::<u32>(CONTROL_BLOCK_SIZE / 4) {
+        *word = 0xAB_CD_EF_01;
+    }
+
+    // Control block 1, block 0 is still described above
+    let cb_pool = unsafe {
+        ControlBlockPool::new(HALPMem::new(
+            dma_cb_pmem.vaddr + CONTROL_BLOCK_SIZE as seL4_Word,
+            dma_cb_pmem.paddr as u32 + CONTROL_BLOCK_SIZE as u32,
+            CONTROL_BLOCK_SIZE,
+        ))
+    };
+
+    let dst = unsafe {
+        Buffer::new(HALPMem::new(
+            fill_pmem.vaddr,
+            fill_pmem.paddr as _,
+            PAGE_SIZE_4K as _,
+        ))
+    };
+
+    debug_println!("Enabling DMA channel 7 (lite)");
+
+    dma_parts.enable.ENABLE.modify(ENABLE::EN7::SET);
+    dma_parts.ch7.reset();
+
+    let transfer = Transfer::fill(dma_parts.ch7, cb_pool, fill_words, dst)
+        .map_err(|(e, ..)| e)
+        .expect("Failed to start the DMA transfer");
+
+    // The channel and buffers are owned by the transfer until it gives them back
+    let (_lite_channel, _cb_pool, _fill_words, dst, result) = transfer.wait();
+
+    assert!(result.is_ok(), "DMA errors present");
+    assert!(dst
+        .as_slice::<u32>(PAGE_SIZE_4K as usize / 4)
+        .iter()
+        .all(|&w| w == 0xAB_CD_EF_01));
+
     debug_println!("All done");
 }
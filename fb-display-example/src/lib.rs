@@ -85,6 +85,8 @@ pub fn init(allocator: &mut Allocator, _global_fault_ep_cap: seL4_CPtr) {
         virt_height: desired_height,
         x_offset: 0,
         y_offset: 0,
+        depth: 32,
+        pixel_order: PixelOrder::RGB,
     };
 
     let resp: Resp = mbox
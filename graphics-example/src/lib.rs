@@ -18,17 +18,14 @@ use bcm2837_hal::dma::DmaExt;
 use bcm2837_hal::mailbox::{Channel, Mailbox};
 use bcm2837_hal::mailbox_msg::*;
 use bcm2837_hal::pmem::PMem as HALPMem;
-use display::Display;
 use display::ObjectDrawing;
+use display::{Display, Rgba8888};
 use embedded_graphics::coord::Coord;
 use gui::*;
 use rgb::RGB8;
 use sel4_sys::*;
 use sel4twinkle_alloc::{Allocator, DMACacheOp, InitCap, PMem, PAGE_BITS_4K, PAGE_SIZE_4K};
 
-const DISPLAY_WIDTH: u32 = 600;
-const DISPLAY_HEIGHT: u32 = 300;
-
 const FAULT_EP_BADGE: seL4_Word = 0xDEAD;
 const IPC_EP_BADGE: seL4_Word = 0xBEEF;
 
@@ -125,14 +122,10 @@ pub fn init(allocator: &mut Allocator, global_fault_ep_cap: seL4_CPtr) {
     debug_println!("\nRequesting framebuffer\n");
 
     // TODO - need to go enable full GPU region in the kernel devices
-    let fb_cfg = FramebufferCmd {
-        phy_width: DISPLAY_WIDTH,
-        phy_height: DISPLAY_HEIGHT,
-        virt_width: DISPLAY_WIDTH,
-        virt_height: DISPLAY_HEIGHT,
-        x_offset: 0,
-        y_offset: 0,
-    };
+    // Two stacked pages, the display flips between them
+    let fb_cfg = FramebufferCmd::from_preferred_mode(&mut mbox)
+        .expect("Failed to negotiate the display mode")
+        .page_flipped();
 
     let resp: Resp = mbox
         .call(Channel::Prop, &fb_cfg)
@@ -146,11 +139,11 @@ pub fn init(allocator: &mut Allocator, global_fault_ep_cap: seL4_CPtr) {
 
     debug_println!("{:#?}", fb_resp);
 
-    assert_eq!(fb_resp.phy_width, DISPLAY_WIDTH);
-    assert_eq!(fb_resp.phy_height, DISPLAY_HEIGHT);
+    assert_eq!(fb_resp.phy_width, fb_cfg.phy_width);
+    assert_eq!(fb_resp.phy_height, fb_cfg.phy_height);
+    assert_eq!(fb_resp.virt_height, fb_cfg.virt_height);
 
-    // TODO - should be virt, pitch * virt_height
-    let mem_size_bytes = (fb_resp.phy_height * fb_resp.pitch) as seL4_Word;
+    let mem_size_bytes = (fb_resp.virt_height * fb_resp.pitch) as seL4_Word;
     let pages = 1 + mem_size_bytes / PAGE_SIZE_4K;
 
     // Map in the GPU memory
@@ -168,29 +161,8 @@ pub fn init(allocator: &mut Allocator, global_fault_ep_cap: seL4_CPtr) {
         DMACacheOp::CleanInvalidate,
     );
 
-    // Allocate the display backbuffer
-    let bb_mem_size_bytes = (4 * fb_resp.phy_height * fb_resp.phy_width) as seL4_Word;
-    let bb_pages = 1 + bb_mem_size_bytes / PAGE_SIZE_4K;
-    assert!(bb_pages * PAGE_SIZE_4K >= bb_mem_size_bytes);
-    let fb_backbuffer_pmem = allocator
-        .pmem_new_dma_pages(
-            bb_pages as _,
-            // Not cacheable
-            0,
-        ).expect("Failed to allocate display backbuffer memory");
-
-    debug_println!("Allocated display backbuffer");
-    debug_println!(
-        "  vaddr = 0x{:X} paddr = 0x{:X}",
-        fb_backbuffer_pmem.vaddr,
-        fb_backbuffer_pmem.paddr
-    );
-
-    allocator.dma_cache_op(
-        fb_backbuffer_pmem.vaddr,
-        bb_mem_size_bytes as _,
-        DMACacheOp::CleanInvalidate,
-    );
+    // The render thread takes over the mailbox to flip the pages
+    let (_, mbox_buffer) = mbox.free();
 
     // Create an IPC buffer / page of memory to store the thread data parameters
     let thread_data_vaddr = allocator
@@ -199,16 +171,19 @@ pub fn init(allocator: &mut Allocator, global_fault_ep_cap: seL4_CPtr) {
 
     let thread_data = unsafe { &mut *(thread_data_vaddr as *mut ThreadData) };
     thread_data.dma_vaddr = dma_vaddr;
+    thread_data.mbox_vaddr = vc_mbox_vaddr;
+    thread_data.mbox_buffer_vaddr = mbox_buffer.vaddr();
+    thread_data.mbox_buffer_paddr = mbox_buffer.paddr() as _;
     thread_data.scratchpad_vaddr = dma_cb_pmem.vaddr;
     thread_data.scratchpad_paddr = dma_cb_pmem.paddr;
     thread_data.fb_width = fb_resp.phy_width;
     thread_data.fb_height = fb_resp.phy_height;
     thread_data.fb_pitch = fb_resp.pitch;
+    thread_data.fb_depth = fb_resp.depth;
     thread_data.fb_pixel_order = fb_resp.pixel_order;
     thread_data.fb_vaddr = gpu_pmem.vaddr;
     thread_data.fb_paddr = fb_resp.bus_paddr.into();
-    thread_data.fb_backbuffer_vaddr = fb_backbuffer_pmem.vaddr;
-    thread_data.fb_backbuffer_paddr = fb_backbuffer_pmem.paddr;
+    thread_data.fb_size = mem_size_bytes;
     // TODO - rename to bus_paddr to match
     //thread_data.fb_paddr = fb_resp.paddr.into();
 
@@ -237,16 +212,19 @@ pub fn init(allocator: &mut Allocator, global_fault_ep_cap: seL4_CPtr) {
 #[derive(Debug)]
 struct ThreadData {
     dma_vaddr: seL4_Word,
+    mbox_vaddr: seL4_Word,
+    mbox_buffer_vaddr: seL4_Word,
+    mbox_buffer_paddr: seL4_Word,
     scratchpad_vaddr: seL4_Word,
     scratchpad_paddr: seL4_Word,
     fb_width: u32,
     fb_height: u32,
     fb_pitch: u32,
+    fb_depth: u32,
     fb_pixel_order: PixelOrder,
     fb_vaddr: seL4_Word,
     fb_paddr: seL4_Word,
-    fb_backbuffer_vaddr: seL4_Word,
-    fb_backbuffer_paddr: seL4_Word,
+    fb_size: seL4_Word,
 }
 
 fn render_thread_function(thread_data_vaddr: seL4_Word) {
@@ -267,15 +245,23 @@ fn render_thread_function(thread_data_vaddr: seL4_Word) {
 
     dma_parts.ch0.reset();
 
-    let width = thread_data.fb_width;
-    let height = thread_data.fb_height;
     let pitch = thread_data.fb_pitch;
 
-    let mut display = Display::new(
+    let mbox = Mailbox::new(
+        MBOX::from(thread_data.mbox_vaddr),
+        HALPMem::new(
+            thread_data.mbox_buffer_vaddr,
+            thread_data.mbox_buffer_paddr as _,
+            PAGE_SIZE_4K as _,
+        ),
+    );
+
+    let mut display: Display<Rgba8888> = Display::new_page_flipped(
         dma_parts.ch0,
         thread_data.fb_width as _,
         thread_data.fb_height as _,
         pitch as _,
+        thread_data.fb_depth,
         thread_data.fb_pixel_order,
         HALPMem::new(
             thread_data.scratchpad_vaddr,
@@ -285,13 +271,11 @@ fn render_thread_function(thread_data_vaddr: seL4_Word) {
         HALPMem::new(
             thread_data.fb_vaddr,
             thread_data.fb_paddr as _,
-            (pitch * height) as _,
-        ),
-        HALPMem::new(
-            thread_data.fb_backbuffer_vaddr,
-            thread_data.fb_backbuffer_paddr as _,
-            (width * height * 4) as _,
+            thread_data.fb_size as _,
         ),
+        mbox,
+        // Flip during the vertical blanking period, no tearing
+        true,
     );
 
     let bar_graph_config = BarGraphConfig {
@@ -320,7 +304,7 @@ fn render_thread_function(thread_data_vaddr: seL4_Word) {
     let mut u_val: u32 = 0;
 
     // Clear back and front buffers
-    display.clear_screen();
+    display.clear_screen().expect("Failed to clear the screen");
 
     loop {
         // Clear the backbuffer
@@ -339,6 +323,6 @@ fn render_thread_function(thread_data_vaddr: seL4_Word) {
 
         u_val += 1;
 
-        display.swap_buffers();
+        display.swap_buffers().expect("Failed to swap buffers");
     }
 }
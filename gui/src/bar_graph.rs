@@ -6,7 +6,7 @@
 // - impl Drawable for BarGraph {}
 
 use core::fmt::Write;
-use display::{Display, DisplayColor, ObjectDrawing};
+use display::{Display, DisplayColor, ObjectDrawing, PixelFormat};
 use embedded_graphics::coord::Coord;
 use embedded_graphics::fonts::Font;
 use embedded_graphics::fonts::Font12x16;
@@ -73,7 +73,7 @@ impl BarGraph {
         write!(self.value_str, "{:.*}", 0, 100.0 * self.value).ok();
     }
 
-    fn draw_fillings(&self, display: &mut Display) {
+    fn draw_fillings<F: PixelFormat>(&self, display: &mut Display<F>) {
         if self.fill_dist <= 0 {
             // empty
             display.draw(
@@ -117,7 +117,7 @@ impl BarGraph {
         }
     }
 
-    fn draw_value_text(&self, display: &mut Display) {
+    fn draw_value_text<F: PixelFormat>(&self, display: &mut Display<F>) {
         let text: Font12x16<DisplayColor> =
             Font12x16::render_str(&self.value_str).with_stroke(Some(self.config.text_color.into()));
 
@@ -158,7 +158,7 @@ impl BarGraph {
         );
     }
 
-    fn draw_outline_rect(&self, display: &mut Display) {
+    fn draw_outline_rect<F: PixelFormat>(&self, display: &mut Display<F>) {
         display.draw(
             Rect::new(self.config.top_left, self.config.bottom_right)
                 .with_stroke(Some(self.config.stroke_color.into()))
@@ -169,7 +169,7 @@ impl BarGraph {
 }
 
 impl ObjectDrawing for BarGraph {
-    fn draw_object(&self, display: &mut Display) {
+    fn draw_object<F: PixelFormat>(&self, display: &mut Display<F>) {
         self.draw_fillings(display);
         self.draw_value_text(display);
         self.draw_outline_rect(display);
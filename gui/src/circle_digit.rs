@@ -4,7 +4,7 @@
 // - iterator
 
 use core::fmt::Write;
-use display::{Display, DisplayColor, ObjectDrawing};
+use display::{Display, DisplayColor, ObjectDrawing, PixelFormat};
 use embedded_graphics::coord::Coord;
 use embedded_graphics::fonts::{Font, Font12x16};
 use embedded_graphics::prelude::*;
@@ -54,7 +54,7 @@ impl CircleDigit {
         self.config.center = coord;
     }
 
-    fn draw_circle(&self, display: &mut Display) {
+    fn draw_circle<F: PixelFormat>(&self, display: &mut Display<F>) {
         let mut circle: Circle<DisplayColor> = Circle::new(self.config.center, self.config.radius)
             .with_stroke(Some(self.config.stroke_color.into()))
             .with_stroke_width(self.config.stroke_width);
@@ -66,7 +66,7 @@ impl CircleDigit {
         display.draw(circle.into_iter());
     }
 
-    fn draw_text(&self, display: &mut Display) {
+    fn draw_text<F: PixelFormat>(&self, display: &mut Display<F>) {
         let text: Font12x16<DisplayColor> =
             Font12x16::render_str(&self.value_str).with_stroke(Some(self.config.text_color.into()));
 
@@ -84,7 +84,7 @@ impl CircleDigit {
 }
 
 impl ObjectDrawing for CircleDigit {
-    fn draw_object(&self, display: &mut Display) {
+    fn draw_object<F: PixelFormat>(&self, display: &mut Display<F>) {
         self.draw_circle(display);
         self.draw_text(display);
     }
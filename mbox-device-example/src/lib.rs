@@ -9,10 +9,12 @@ use bcm2837_hal::bcm2837::mbox::{
     BASE_OFFSET as MBOX_BASE_OFFSET, BASE_PADDR as MBOX_BASE_PADDR, MBOX,
 };
 use bcm2837_hal::bcm2837::uart1::{PADDR as UART1_PADDR, UART1};
+use bcm2837_hal::clocks::Clocks;
 use bcm2837_hal::mailbox::{Channel, Mailbox};
 use bcm2837_hal::mailbox_msg::*;
 use bcm2837_hal::pmem::PMem as HALPMem;
 use bcm2837_hal::serial::Serial;
+use bcm2837_hal::time::U32Ext;
 use core::fmt::Write;
 use sel4_sys::*;
 use sel4twinkle_alloc::{Allocator, DMACacheOp, PMem, PAGE_BITS_4K, PAGE_SIZE_4K};
@@ -68,7 +70,12 @@ pub fn init(allocator: &mut Allocator, _global_fault_ep_cap: seL4_CPtr) {
     debug_println!("  vaddr = 0x{:X} paddr = 0x{:X}", uart1_vaddr, UART1_PADDR,);
 
     // Serial
-    let mut serial: Serial<UART1> = Serial::uart1(UART1::from(uart1_vaddr), 0, &mut gpio);
+    let mut serial: Serial<UART1> = Serial::uart1(
+        UART1::from(uart1_vaddr),
+        115_200.bps(),
+        &mut gpio,
+        Clocks::read(),
+    ).expect("Failed to configure UART1");
 
     writeln!(serial, "\nThis is output from a Serial<UART1>\n").ok();
 
@@ -115,6 +122,18 @@ pub fn init(allocator: &mut Allocator, _global_fault_ep_cap: seL4_CPtr) {
         .expect("Mailbox::call failed");
     writeln!(serial, "Response = {:#?}", res).ok();
 
+    writeln!(serial, "\nMailbox send GetMaxTemperatureCmd\n").ok();
+    let res: Resp = mbox
+        .call(Channel::Prop, &GetMaxTemperatureCmd { id: 0 })
+        .expect("Mailbox::call failed");
+    writeln!(serial, "Response = {:#?}", res).ok();
+
+    writeln!(serial, "\nMailbox send GetThrottledCmd\n").ok();
+    let res: Resp = mbox
+        .call(Channel::Prop, &GetThrottledCmd)
+        .expect("Mailbox::call failed");
+    writeln!(serial, "Response = {:#?}", res).ok();
+
     writeln!(serial, "\nMailbox send GetArmMemCmd\n").ok();
     let res: Resp = mbox
         .call(Channel::Prop, &GetArmMemCmd)
@@ -133,6 +152,20 @@ pub fn init(allocator: &mut Allocator, _global_fault_ep_cap: seL4_CPtr) {
         .expect("Mailbox::call failed");
     writeln!(serial, "Response = {:#?}", res).ok();
 
+    writeln!(serial, "\nMailbox send batched commands\n").ok();
+    {
+        let res = mbox
+            .call_batch(
+                Channel::Prop,
+                &(GetSerialNumCmd, GetTemperatureCmd { id: 0 }, GetArmMemCmd),
+            ).expect("Mailbox::call_batch failed");
+        for tag in res.tags() {
+            writeln!(serial, "Response = {:#?}", tag).ok();
+        }
+        let temp: GetTemperatureResp = res.get().expect("Missing GetTemperatureResp");
+        writeln!(serial, "Temperature = {} mC", temp.value).ok();
+    }
+
     writeln!(serial, "\nMailbox send FramebufferCmd\n").ok();
     let fb_res: Resp = mbox
         .call(
@@ -144,6 +177,8 @@ pub fn init(allocator: &mut Allocator, _global_fault_ep_cap: seL4_CPtr) {
                 virt_height: 240,
                 x_offset: 0,
                 y_offset: 0,
+                depth: 32,
+                pixel_order: PixelOrder::RGB,
             },
         ).expect("Mailbox::call failed");
     writeln!(serial, "Response = {:#?}", fb_res).ok();